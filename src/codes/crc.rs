@@ -0,0 +1,307 @@
+use std::fmt;
+
+use num_traits::Zero;
+
+use crate::fields::Gf2Polynomial;
+
+/// Reverses the `width` low bits of `x`.
+#[inline]
+fn reflect(x: u64, width: usize) -> u64 {
+	x.reverse_bits() >> (64 - width)
+}
+
+/// A cyclic redundancy check, described by the usual parameters of the
+/// Rocksoft model: the generator polynomial, whose degree is the width of the
+/// checksum, the initial value of the register, whether the input bytes and
+/// the output are reflected, and the value XORed to the output.
+///
+/// The checksum of a message `M` of `n` bits is the remainder of
+/// `init X^n + M X^width` by the generator, see `checksum_polynomial`. The
+/// other methods compute the same value with lookup tables derived from the
+/// generator: `checksum_table` processes one byte at a time and `checksum`
+/// eight bytes at a time (slicing-by-8).
+///
+/// # Example
+/// ```rust
+/// use polyx::{codes::Crc, fields::Gf2Polynomial, *};
+/// // X^16 + X^15 + X^2 + 1
+/// let generator = Gf2Polynomial::from(polynomial![1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1]);
+/// let crc = Crc::new(generator, 0, true, true, 0);
+/// assert_eq!(crc.checksum(b"123456789"), 0xbb3d);
+/// assert_eq!(crc.checksum(b"123456789"), Crc::crc16_arc().checksum(b"123456789"));
+/// ```
+#[derive(Clone)]
+pub struct Crc {
+	generator: Gf2Polynomial,
+	width: usize,
+	init: u64,
+	reflect_in: bool,
+	reflect_out: bool,
+	xorout: u64,
+	/// `tables[k][b]` is the contribution of the byte `b` followed by `k` zero
+	/// bytes. When the input is not reflected, the register is kept in the high
+	/// bits of a word so that widths smaller than 8 need no special case.
+	tables: Vec<[u64; 256]>,
+}
+
+impl Crc {
+	/// Creates a CRC from its generator polynomial, whose degree `width` must be
+	/// between 1 and 64, and its parameters in the Rocksoft model.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the degree of the generator is not between 1
+	/// and 64, or if `init` or `xorout` do not fit in `width` bits.
+	pub fn new(
+		generator: Gf2Polynomial,
+		init: u64,
+		reflect_in: bool,
+		reflect_out: bool,
+		xorout: u64,
+	) -> Self {
+		let width = generator.degree();
+		if !(1..=64).contains(&width) {
+			panic!("The degree of a CRC generator must be between 1 and 64, got {generator}");
+		}
+		let mask = u64::MAX >> (64 - width);
+		if init & !mask != 0 || xorout & !mask != 0 {
+			panic!("The initial value and the output XOR of a CRC must fit in {width} bits");
+		}
+		// Coefficients of the generator without X^width
+		let low = (&generator + &Gf2Polynomial::from_exponents(&[width]))
+			.words()
+			.first()
+			.copied()
+			.unwrap_or(0);
+		let mut tables = vec![[0u64; 256]; 8];
+		if reflect_in {
+			let poly = reflect(low, width);
+			for (b, entry) in tables[0].iter_mut().enumerate() {
+				let mut crc = b as u64;
+				for _ in 0..8 {
+					crc = if crc & 1 == 1 {
+						(crc >> 1) ^ poly
+					} else {
+						crc >> 1
+					};
+				}
+				*entry = crc;
+			}
+			for k in 1..8 {
+				for b in 0..256 {
+					let prev = tables[k - 1][b];
+					tables[k][b] = (prev >> 8) ^ tables[0][(prev & 0xff) as usize];
+				}
+			}
+		} else {
+			let poly = low << (64 - width);
+			for (b, entry) in tables[0].iter_mut().enumerate() {
+				let mut crc = (b as u64) << 56;
+				for _ in 0..8 {
+					crc = if crc >> 63 == 1 {
+						(crc << 1) ^ poly
+					} else {
+						crc << 1
+					};
+				}
+				*entry = crc;
+			}
+			for k in 1..8 {
+				for b in 0..256 {
+					let prev = tables[k - 1][b];
+					tables[k][b] = (prev << 8) ^ tables[0][(prev >> 56) as usize];
+				}
+			}
+		}
+		Crc {
+			generator,
+			width,
+			init,
+			reflect_in,
+			reflect_out,
+			xorout,
+			tables,
+		}
+	}
+
+	/// Creates a CRC from the usual hexadecimal notation of its generator, which
+	/// omits the term `X^width`.
+	///
+	/// # Panics
+	///
+	/// Same as `new`, and if `poly` does not fit in `width` bits.
+	pub fn from_normal(
+		width: usize,
+		poly: u64,
+		init: u64,
+		reflect_in: bool,
+		reflect_out: bool,
+		xorout: u64,
+	) -> Self {
+		if width < 64 && poly >> width != 0 {
+			panic!("The generator {poly:#x} does not fit in {width} bits");
+		}
+		let generator =
+			&Gf2Polynomial::from_words(vec![poly]) + &Gf2Polynomial::from_exponents(&[width]);
+		Self::new(generator, init, reflect_in, reflect_out, xorout)
+	}
+
+	/// CRC-32 (ISO-HDLC), used by Ethernet, zlib and PNG.
+	pub fn crc32() -> Self {
+		Self::from_normal(32, 0x04c1_1db7, 0xffff_ffff, true, true, 0xffff_ffff)
+	}
+
+	/// CRC-32C (iSCSI), with Castagnoli's generator.
+	pub fn crc32c() -> Self {
+		Self::from_normal(32, 0x1edc_6f41, 0xffff_ffff, true, true, 0xffff_ffff)
+	}
+
+	/// CRC-16/ARC.
+	pub fn crc16_arc() -> Self {
+		Self::from_normal(16, 0x8005, 0, true, true, 0)
+	}
+
+	/// CRC-16/IBM-3740, also known as CRC-16/CCITT-FALSE.
+	pub fn crc16_ibm_3740() -> Self {
+		Self::from_normal(16, 0x1021, 0xffff, false, false, 0)
+	}
+
+	/// CRC-8/SMBUS.
+	pub fn crc8_smbus() -> Self {
+		Self::from_normal(8, 0x07, 0, false, false, 0)
+	}
+
+	/// CRC-64/XZ.
+	pub fn crc64_xz() -> Self {
+		Self::from_normal(64, 0x42f0_e1eb_a9ea_3693, u64::MAX, true, true, u64::MAX)
+	}
+
+	/// Returns the generator polynomial.
+	#[inline]
+	pub fn generator(&self) -> &Gf2Polynomial {
+		&self.generator
+	}
+
+	/// Returns the number of bits of the checksum, i.e. the degree of the
+	/// generator.
+	#[inline]
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	fn initial_register(&self) -> u64 {
+		if self.reflect_in {
+			reflect(self.init, self.width)
+		} else {
+			self.init << (64 - self.width)
+		}
+	}
+
+	fn finalize(&self, register: u64) -> u64 {
+		let value = if self.reflect_in {
+			reflect(register, self.width)
+		} else {
+			register >> (64 - self.width)
+		};
+		let value = if self.reflect_out {
+			reflect(value, self.width)
+		} else {
+			value
+		};
+		value ^ self.xorout
+	}
+
+	fn update_table(&self, mut register: u64, data: &[u8]) -> u64 {
+		let table = &self.tables[0];
+		for &byte in data {
+			register = if self.reflect_in {
+				(register >> 8) ^ table[((register ^ byte as u64) & 0xff) as usize]
+			} else {
+				(register << 8) ^ table[((register >> 56) ^ byte as u64) as usize]
+			};
+		}
+		register
+	}
+
+	/// Computes the checksum of `data` by polynomial arithmetic in GF(2)\[X\].
+	/// This is slow and is meant as a reference for the table-driven methods.
+	pub fn checksum_polynomial(&self, data: &[u8]) -> u64 {
+		let n = 8 * data.len();
+		// The first bit of the message has the highest degree
+		let exponents: Vec<usize> = data
+			.iter()
+			.enumerate()
+			.flat_map(|(i, &byte)| {
+				let byte = if self.reflect_in {
+					byte.reverse_bits()
+				} else {
+					byte
+				};
+				(0..8)
+					.filter(move |j| (byte >> j) & 1 == 1)
+					.map(move |j| n - 8 * (i + 1) + j + self.width)
+			})
+			.collect();
+		let message = Gf2Polynomial::from_exponents(&exponents);
+		let init = Gf2Polynomial::from_words(vec![self.init]) << n;
+		let remainder = &(&message + &init) % &self.generator;
+		let value = if remainder.is_zero() {
+			0
+		} else {
+			remainder.words()[0]
+		};
+		let value = if self.reflect_out {
+			reflect(value, self.width)
+		} else {
+			value
+		};
+		value ^ self.xorout
+	}
+
+	/// Computes the checksum of `data` with a lookup table, one byte at a time.
+	pub fn checksum_table(&self, data: &[u8]) -> u64 {
+		self.finalize(self.update_table(self.initial_register(), data))
+	}
+
+	/// Computes the checksum of `data` with eight lookup tables, eight bytes at
+	/// a time (slicing-by-8).
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::codes::Crc;
+	/// assert_eq!(Crc::crc32().checksum(b"123456789"), 0xcbf4_3926);
+	/// assert_eq!(Crc::crc64_xz().checksum(b"123456789"), 0x995d_c9bb_df19_39fa);
+	/// ```
+	pub fn checksum(&self, data: &[u8]) -> u64 {
+		let mut register = self.initial_register();
+		let mut chunks = data.chunks_exact(8);
+		for chunk in &mut chunks {
+			let chunk: [u8; 8] = chunk.try_into().unwrap();
+			register = if self.reflect_in {
+				let x = register ^ u64::from_le_bytes(chunk);
+				(0..8).fold(0, |acc, k| {
+					acc ^ self.tables[7 - k][((x >> (8 * k)) & 0xff) as usize]
+				})
+			} else {
+				let x = register ^ u64::from_be_bytes(chunk);
+				(0..8).fold(0, |acc, k| {
+					acc ^ self.tables[k][((x >> (8 * k)) & 0xff) as usize]
+				})
+			};
+		}
+		self.finalize(self.update_table(register, chunks.remainder()))
+	}
+}
+
+impl fmt::Debug for Crc {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Crc")
+			.field("generator", &self.generator)
+			.field("width", &self.width)
+			.field("init", &self.init)
+			.field("reflect_in", &self.reflect_in)
+			.field("reflect_out", &self.reflect_out)
+			.field("xorout", &self.xorout)
+			.finish()
+	}
+}
@@ -0,0 +1,8 @@
+//! Error detecting and correcting codes built on polynomial arithmetic over
+//! finite fields.
+
+mod crc;
+mod reed_solomon;
+
+pub use crc::Crc;
+pub use reed_solomon::ReedSolomon;
@@ -0,0 +1,230 @@
+use num_traits::{Inv, One, Zero};
+
+use crate::{
+	fields::{Gf2Polynomial, Gf2m},
+	Polynomial,
+};
+
+/// A systematic Reed–Solomon code over GF(2^m) with `n - k` parity symbols,
+/// which corrects `e` errors and `f` erasures as long as `2e + f <= n - k`.
+///
+/// Codewords are the message followed by the parity symbols, the first symbol
+/// being the coefficient of the highest degree `X^(n - 1)`. The generator
+/// polynomial is the product of the `X - α^(b + i)` for `0 <= i < n - k`,
+/// where `α` is the primitive element of the field returned by
+/// `Gf2m::primitive_element` and `b` is the first consecutive root.
+///
+/// Decoding computes the syndromes, finds the error locator with
+/// Berlekamp–Massey's algorithm initialized with the erasure locator, its
+/// roots with a Chien search and the error values with Forney's formula.
+///
+/// # Example
+/// ```rust
+/// use polyx::{codes::ReedSolomon, fields::Gf256};
+/// let rs = ReedSolomon::<0x11d>::new(12, 8);
+/// let message: Vec<Gf256> = b"polyx RS".iter().map(|&b| b.into()).collect();
+/// let mut codeword = rs.encode(&message);
+/// assert_eq!(codeword[..8], message);
+/// // One error and two erasures
+/// codeword[1] = Gf256::new(0);
+/// codeword[5] = Gf256::new(0);
+/// codeword[10] = Gf256::new(0);
+/// assert_eq!(rs.decode(&codeword, &[5, 10]), Ok(message));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReedSolomon<const POLY: u64> {
+	n: usize,
+	k: usize,
+	first_root: u64,
+	alpha: Gf2m<POLY>,
+	generator: Polynomial<Gf2m<POLY>>,
+}
+
+impl<const POLY: u64> ReedSolomon<POLY> {
+	/// Creates a code of length `n` with `k` message symbols, whose generator
+	/// has the roots `α, α^2, ..., α^(n - k)`.
+	///
+	/// # Panics
+	///
+	/// Same as `with_first_root`.
+	pub fn new(n: usize, k: usize) -> Self {
+		Self::with_first_root(n, k, 1)
+	}
+
+	/// Creates a code of length `n` with `k` message symbols, whose generator
+	/// has the roots `α^b, α^(b + 1), ..., α^(b + n - k - 1)`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `POLY` is not irreducible, if m is greater
+	/// than 32, or unless `0 < k < n < 2^m`.
+	pub fn with_first_root(n: usize, k: usize, b: u64) -> Self {
+		if !Gf2Polynomial::from_words(vec![POLY]).is_irreducible() {
+			panic!("The modulus {POLY:#x} of the field is not irreducible");
+		}
+		let order = (1u64 << Gf2m::<POLY>::DEGREE) - 1;
+		if k == 0 || k >= n || n as u64 > order {
+			panic!("A Reed-Solomon code over GF(2^m) needs 0 < k < n < 2^m, got n = {n}, k = {k}");
+		}
+		let alpha = Gf2m::<POLY>::primitive_element();
+		let roots: Vec<Gf2m<POLY>> = (0..(n - k) as u64).map(|i| alpha.pow(b + i)).collect();
+		ReedSolomon {
+			n,
+			k,
+			first_root: b,
+			alpha,
+			generator: Polynomial::from_roots(&roots),
+		}
+	}
+
+	/// Returns the generator polynomial of the code.
+	#[inline]
+	pub fn generator(&self) -> &Polynomial<Gf2m<POLY>> {
+		&self.generator
+	}
+
+	/// Returns the primitive element `α` whose powers are the roots of the
+	/// generator.
+	#[inline]
+	pub fn alpha(&self) -> Gf2m<POLY> {
+		self.alpha
+	}
+
+	/// Converts symbols in transmission order to a polynomial.
+	fn to_polynomial(&self, symbols: &[Gf2m<POLY>]) -> Polynomial<Gf2m<POLY>> {
+		Polynomial::from(symbols.iter().rev().copied().collect::<Vec<_>>())
+	}
+
+	/// Encodes `k` message symbols into a codeword of `n` symbols, by appending
+	/// the remainder of `message * X^(n - k)` by the generator.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the message does not have `k` symbols.
+	pub fn encode(&self, message: &[Gf2m<POLY>]) -> Vec<Gf2m<POLY>> {
+		if message.len() != self.k {
+			panic!(
+				"Expected a message of {} symbols, got {}",
+				self.k,
+				message.len()
+			);
+		}
+		let shifted = self.to_polynomial(message) << (self.n - self.k);
+		let (_, remainder) =
+			Polynomial::euclidean_division_immutable_float(&shifted, &self.generator);
+		let mut codeword = message.to_vec();
+		codeword.extend(
+			(0..self.n - self.k)
+				.rev()
+				.map(|i| remainder.0.get(i).copied().unwrap_or_else(Gf2m::zero)),
+		);
+		codeword
+	}
+
+	/// Computes the syndromes `r(α^(b + i))` for `0 <= i < n - k`, which all
+	/// vanish if and only if `r` is a codeword.
+	fn syndromes(&self, received: &Polynomial<Gf2m<POLY>>) -> Vec<Gf2m<POLY>> {
+		(0..(self.n - self.k) as u64)
+			.map(|i| received.eval(self.alpha.pow(self.first_root + i)))
+			.collect()
+	}
+
+	/// Corrects the errors and the erasures of a received word and returns the
+	/// `k` message symbols. `erasures` are the indices of the symbols known to
+	/// be wrong, whose value is ignored.
+	///
+	/// Returns an error if the word has too many errors to be corrected.
+	/// Beyond the correction capacity, the decoder may also return a wrong
+	/// message when the received word is closer to another codeword.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the received word does not have `n` symbols
+	/// or if an erasure is out of range.
+	pub fn decode(
+		&self,
+		received: &[Gf2m<POLY>],
+		erasures: &[usize],
+	) -> Result<Vec<Gf2m<POLY>>, String> {
+		if received.len() != self.n {
+			panic!(
+				"Expected a word of {} symbols, got {}",
+				self.n,
+				received.len()
+			);
+		}
+		let mut erasures = erasures.to_vec();
+		erasures.sort_unstable();
+		erasures.dedup();
+		if let Some(&j) = erasures.iter().find(|&&j| j >= self.n) {
+			panic!("Erasure {j} is out of a word of {} symbols", self.n);
+		}
+		if erasures.len() > self.n - self.k {
+			return Err(format!(
+				"{} erasures exceed the {} parity symbols",
+				erasures.len(),
+				self.n - self.k
+			));
+		}
+
+		let mut word = self.to_polynomial(received);
+		let syndromes = self.syndromes(&word);
+		if syndromes.iter().all(|s| s.is_zero()) {
+			return Ok(received[..self.k].to_vec());
+		}
+
+		// The symbol of index j is the coefficient of degree n - 1 - j, which is
+		// located by α^(n - 1 - j)
+		let locators: Vec<Gf2m<POLY>> = erasures
+			.iter()
+			.map(|&j| self.alpha.pow((self.n - 1 - j) as u64))
+			.collect();
+		let erasure_locator = locators
+			.iter()
+			.fold(Polynomial(vec![Gf2m::one()]), |acc, &x| {
+				acc * Polynomial(vec![Gf2m::one(), x])
+			});
+		let locator =
+			Polynomial::berlekamp_massey_from(&syndromes, erasure_locator, erasures.len());
+
+		// Chien search: the roots of the locator are the inverses of the locators
+		// of the wrong symbols
+		let alpha_inv = self.alpha.inv();
+		let positions: Vec<usize> = (0..self.n)
+			.filter(|&i| locator.eval(alpha_inv.pow(i as u64)).is_zero())
+			.collect();
+		if positions.len() != locator.degree() {
+			return Err("Too many errors to correct".to_string());
+		}
+
+		// Forney's formula with the error evaluator S(X) Λ(X) mod X^(n - k)
+		let evaluator =
+			Polynomial::short_product(&Polynomial::from(syndromes), &locator, self.n - self.k);
+		let derivative = locator.derivative();
+		for i in positions {
+			let x = self.alpha.pow(i as u64);
+			let x_inv = x.inv();
+			let denominator = derivative.eval(x_inv);
+			if denominator.is_zero() {
+				return Err("Too many errors to correct".to_string());
+			}
+			let magnitude = evaluator.eval(x_inv) / denominator * (x * x_inv.pow(self.first_root));
+			if word.0.len() <= i {
+				word.0.resize(i + 1, Gf2m::zero());
+			}
+			word.0[i] = word.0[i] + magnitude;
+		}
+		let word = Polynomial::from(word.0);
+		if self.syndromes(&word).iter().any(|s| !s.is_zero()) {
+			return Err("Too many errors to correct".to_string());
+		}
+		Ok((0..self.k)
+			.map(|j| {
+				word.0
+					.get(self.n - 1 - j)
+					.copied()
+					.unwrap_or_else(Gf2m::zero)
+			})
+			.collect())
+	}
+}
@@ -1,29 +1,41 @@
 use std::fmt;
 
-use crate::parser::Ops;
+use crate::expr::BinaryOp;
 
-#[derive(Debug, Clone)]
-pub(crate) enum PolynomialError {
-	NoBinaryOperator,
-	BinaryOperatorZeroOperand(Ops),
-	BinaryOperatorOneOperand(Ops, String),
+/// The kinds of errors raised while parsing or evaluating a polynomial
+/// expression, see `expr::ParseError::kind`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolynomialError {
+	BinaryOperatorZeroOperand(BinaryOp),
+	BinaryOperatorOneOperand(BinaryOp),
 	ImpossiblePower(String, String),
 	ImpossiblePower2Polynomials(String, String),
 	ImpossibleDivision(String, String),
 	ImpossibleOpen,
 	ImpossibleClose,
-	UnaryMinusFailed(Ops),
+	UnaryMinusFailed(BinaryOp),
 	UnsupportedCharacter(char),
+	UnboundNames(Vec<String>),
+	WrongArity(String, usize, usize),
+	FunctionFailed(String, String),
+	MissingOperator,
 	EmptyStringInput,
+	NestingTooDeep,
+	LiteralOverflow(String),
+	PowerOverflow(String, String),
+	OperationOverflow(BinaryOp, String, String),
+	NegationOverflow(String),
+	InexactLiteral(String),
+	MalformedLiteral(String),
+	ReadFailed(String),
 }
 
 impl fmt::Display for PolynomialError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		use PolynomialError::*;
 		match self {
-			NoBinaryOperator => write!(f, "NoBinaryOperator"),
 			BinaryOperatorZeroOperand(op) => write!(f, "BinaryOperatorZeroOperand({})", op),
-			BinaryOperatorOneOperand(op, p) => write!(f, "BinaryOperatorOneOperand({}, {})", op, p),
+			BinaryOperatorOneOperand(op) => write!(f, "BinaryOperatorOneOperand({})", op),
 			ImpossiblePower(p, n) => write!(f, "ImpossiblePower({}, {:?})", p, n),
 			ImpossiblePower2Polynomials(p1, p2) => {
 				write!(f, "ImpossiblePower2Polynomials({}, {})", p1, p2)
@@ -33,7 +45,21 @@ impl fmt::Display for PolynomialError {
 			ImpossibleClose => write!(f, "ImpossibleClose"),
 			UnaryMinusFailed(op) => write!(f, "UnaryMinusFailed({})", op),
 			UnsupportedCharacter(c) => write!(f, "UnsupportedCharacter({})", c),
+			UnboundNames(names) => write!(f, "UnboundNames({})", names.join(", ")),
+			WrongArity(name, expected, got) => {
+				write!(f, "WrongArity({}, {}, {})", name, expected, got)
+			}
+			FunctionFailed(name, message) => write!(f, "FunctionFailed({}, {})", name, message),
+			MissingOperator => write!(f, "MissingOperator"),
 			EmptyStringInput => write!(f, "EmptyStringInput"),
+			NestingTooDeep => write!(f, "NestingTooDeep"),
+			LiteralOverflow(s) => write!(f, "LiteralOverflow({})", s),
+			PowerOverflow(p, n) => write!(f, "PowerOverflow({}, {})", p, n),
+			OperationOverflow(op, p1, p2) => write!(f, "OperationOverflow({}, {}, {})", op, p1, p2),
+			NegationOverflow(p) => write!(f, "NegationOverflow({})", p),
+			InexactLiteral(s) => write!(f, "InexactLiteral({})", s),
+			MalformedLiteral(s) => write!(f, "MalformedLiteral({})", s),
+			ReadFailed(s) => write!(f, "ReadFailed({})", s),
 		}
 	}
 }
@@ -0,0 +1,1321 @@
+//! Polynomial expressions parsed from strings, which can be inspected,
+//! printed and evaluated with any coefficient type implementing
+//! `CoefficientLiteral`.
+//!
+//! # Example
+//! ```rust
+//! use polyx::{expr::parse_expr, fields::Fp, *};
+//! let expr = parse_expr("(X + 1)^3 - 3X").unwrap();
+//! assert_eq!(expr.to_string(), "(X + 1)^3 - 3 * X");
+//! assert_eq!(expr.eval::<i32>().unwrap(), polynomial![1, 0, 3, 1]);
+//! assert_eq!(
+//!     expr.eval::<Fp<3>>().unwrap(),
+//!     Polynomial::<Fp<3>>::parse_string("X^3 + 1".to_string()).unwrap()
+//! );
+//! ```
+
+use std::{collections::HashMap, fmt, mem, rc::Rc};
+
+use num_traits::{Num, Zero};
+
+use crate::{
+	errors::PolynomialError::{self, *},
+	parser::Parser,
+	polynomial,
+	traits::CoefficientLiteral,
+	Polynomial,
+};
+
+/// A range of byte offsets `start..end` in the parsed string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+	pub start: usize,
+	pub end: usize,
+}
+
+impl Span {
+	/// Returns the smallest span containing both spans.
+	#[inline]
+	pub fn to(self, other: Span) -> Span {
+		Span {
+			start: self.start.min(other.start),
+			end: self.end.max(other.end),
+		}
+	}
+}
+
+impl fmt::Display for Span {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}..{}", self.start, self.end)
+	}
+}
+
+/// The binary operators of the expression language.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum BinaryOp {
+	Add,
+	Sub,
+	Mul,
+	Div,
+	/// The remainder of the Euclidean division, written `%` or `mod`.
+	Rem,
+	Pow,
+}
+
+impl BinaryOp {
+	/// Returns the symbol of the operator.
+	pub fn symbol(&self) -> &'static str {
+		match self {
+			BinaryOp::Add => "+",
+			BinaryOp::Sub => "-",
+			BinaryOp::Mul => "*",
+			BinaryOp::Div => "/",
+			BinaryOp::Rem => "%",
+			BinaryOp::Pow => "^",
+		}
+	}
+
+	/// Returns the binding power of the operator, higher binding tighter.
+	fn precedence(&self) -> u32 {
+		match self {
+			BinaryOp::Add | BinaryOp::Sub => 1,
+			BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => 2,
+			BinaryOp::Pow => 4,
+		}
+	}
+}
+
+impl fmt::Display for BinaryOp {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "'{}'", self.symbol())
+	}
+}
+
+/// The longest shift of the decimal point of a literal by its exponent, beyond
+/// which it is reported as an overflow rather than expanded to its digits.
+const MAX_SHIFT: usize = 10_000;
+
+/// The largest degree of the powers of polynomials and of the Chebyshev and
+/// Bernstein polynomials of the built-in functions, beyond which they are
+/// reported as errors rather than computed.
+const MAX_DEGREE: usize = 10_000;
+
+/// A numeric literal, as written in the source.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Literal {
+	/// The digits before the decimal point in base `radix`, without
+	/// underscores. It is empty in a literal like `.5`.
+	pub integer: String,
+	/// The digits after the decimal point, empty for an integer.
+	pub decimals: String,
+	/// The power of ten of the scientific notation, e.g. -3 in `1.5e-3`.
+	pub exponent: i64,
+	/// 10, or 16 and 2 for the prefixes `0x` and `0b`.
+	pub radix: u32,
+	/// The number of imaginary units following the literal, e.g. 1 in `2.5i`.
+	pub imaginary: u32,
+}
+
+impl Literal {
+	/// Returns the decimal integer literal `integer` followed by `imaginary`
+	/// imaginary units.
+	pub(crate) fn integer(integer: String, imaginary: u32) -> Self {
+		Literal {
+			integer,
+			decimals: String::new(),
+			exponent: 0,
+			radix: 10,
+			imaginary,
+		}
+	}
+
+	/// Converts the literal, without its imaginary units, to a coefficient
+	/// without going through `f64`, so that exact types such as `BigInt` or
+	/// `BigRational` get the exact value and fixed-size integers report an
+	/// overflow instead of wrapping.
+	///
+	/// Floating point types parse the literal directly, rationals as
+	/// `mantissa/10^k` and other types as the exact quotient of these two
+	/// integers, which fails for a literal like `2.5` with integer
+	/// coefficients.
+	pub(crate) fn parse<T>(&self, negative: bool) -> Result<T, PolynomialError>
+	where
+		T: Num + Clone,
+	{
+		let sign = if negative { "-" } else { "" };
+		let literal = format!(
+			"{sign}{}",
+			Literal {
+				imaginary: 0,
+				..self.clone()
+			}
+		);
+		let overflow = || LiteralOverflow(literal.clone());
+		// Rationals only parse fractions, hence the denominators
+		let parse_integer = |digits: &str| {
+			T::from_str_radix(digits, self.radix)
+				.or_else(|_| T::from_str_radix(&format!("{digits}/1"), self.radix))
+				.map_err(|_| overflow())
+		};
+		// Infinite floats are the only values for which `x - x` is not zero
+		let finite = |value: T| {
+			if (value.clone() - value.clone()).is_zero() {
+				Ok(value)
+			} else {
+				Err(overflow())
+			}
+		};
+		let integer = if self.integer.is_empty() {
+			"0"
+		} else {
+			&self.integer
+		};
+		if self.decimals.is_empty() && self.exponent == 0 {
+			return parse_integer(&format!("{sign}{integer}"));
+		}
+		let mut direct = format!("{sign}{integer}");
+		if !self.decimals.is_empty() {
+			direct = format!("{direct}.{}", self.decimals);
+		}
+		if self.exponent != 0 {
+			direct = format!("{direct}e{}", self.exponent);
+		}
+		if let Ok(value) = T::from_str_radix(&direct, 10) {
+			return finite(value);
+		}
+		// The literal is `mantissa * 10^-scale`
+		let mut mantissa = format!("{sign}{integer}{}", self.decimals);
+		let scale = i64::try_from(self.decimals.len())
+			.ok()
+			.and_then(|k| k.checked_sub(self.exponent))
+			.filter(|k| k.unsigned_abs() <= MAX_SHIFT as u64)
+			.ok_or_else(overflow)?;
+		if scale <= 0 {
+			mantissa.push_str(&"0".repeat(scale.unsigned_abs() as usize));
+			return parse_integer(&mantissa);
+		}
+		let scale = format!("1{}", "0".repeat(scale as usize));
+		if let Ok(value) = T::from_str_radix(&format!("{mantissa}/{scale}"), 10) {
+			return Ok(value);
+		}
+		let mantissa: T = parse_integer(&mantissa)?;
+		let scale: T = parse_integer(&scale)?;
+		if scale.is_zero() {
+			return Err(InexactLiteral(literal));
+		}
+		let value = mantissa.clone() / scale.clone();
+		if value.clone() * scale == mantissa {
+			Ok(value)
+		} else {
+			Err(InexactLiteral(literal))
+		}
+	}
+}
+
+impl fmt::Display for Literal {
+	/// Prints the literal as written, without the underscores.
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.radix {
+			16 => write!(f, "0x")?,
+			2 => write!(f, "0b")?,
+			_ => {}
+		}
+		let integer = if self.integer.is_empty() {
+			"0"
+		} else {
+			&self.integer
+		};
+		write!(f, "{integer}")?;
+		if !self.decimals.is_empty() {
+			write!(f, ".{}", self.decimals)?;
+		}
+		if self.exponent != 0 {
+			write!(f, "e{}", self.exponent)?;
+		}
+		write!(f, "{}", "i".repeat(self.imaginary as usize))
+	}
+}
+
+/// The kinds of nodes of an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+	Literal(Literal),
+	/// The indeterminate, as written in the source.
+	Variable(String),
+	/// A named parameter, whose value is given by an `Environment`.
+	Name(String),
+	/// A function of an `Environment` applied to its arguments, as in
+	/// `f(X, 2)`. A constant applied to one argument is a product.
+	Call(String, Vec<Expr>),
+	Neg(Box<Expr>),
+	Binary(BinaryOp, Box<Expr>, Box<Expr>),
+	/// An expression between parentheses.
+	Group(Box<Expr>),
+}
+
+/// A node of an expression, with the span of the source it was parsed from.
+///
+/// Evaluating, printing and dropping an expression go through chains of
+/// operators such as long sums without recursion, but recurse on the other
+/// nodes, so that the nesting of parentheses, calls, minus signs and
+/// exponents is limited to 128 levels by the parser. The derived `Clone`,
+/// `PartialEq` and `Debug` recurse on every node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+	pub kind: ExprKind,
+	pub span: Span,
+}
+
+/// An error raised while parsing or evaluating an expression, with the span of
+/// the source where it occurred, and its line and column when it was read by
+/// `Polynomial::parse_many`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+	pub(crate) error: PolynomialError,
+	pub(crate) span: Span,
+	pub(crate) location: Option<(usize, usize)>,
+}
+
+impl ParseError {
+	#[inline]
+	pub(crate) fn new(error: PolynomialError, span: Span) -> Self {
+		ParseError {
+			error,
+			span,
+			location: None,
+		}
+	}
+
+	/// Returns the kind of the error, e.g. `LiteralOverflow` or the
+	/// `UnboundNames` of an expression.
+	#[inline]
+	pub fn kind(&self) -> &PolynomialError {
+		&self.error
+	}
+
+	/// Returns the span of the source where the error occurred. For the errors
+	/// of `Polynomial::parse_many`, it is a span of the line.
+	#[inline]
+	pub fn span(&self) -> Span {
+		self.span
+	}
+
+	/// Returns the line where the error occurred, counted from 1, if it was
+	/// read by `Polynomial::parse_many`.
+	#[inline]
+	pub fn line(&self) -> Option<usize> {
+		self.location.map(|(line, _)| line)
+	}
+
+	/// Returns the column, in characters counted from 1, of the start of the
+	/// span, if it was read by `Polynomial::parse_many`.
+	#[inline]
+	pub fn column(&self) -> Option<usize> {
+		self.location.map(|(_, column)| column)
+	}
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.location {
+			Some((line, column)) => write!(f, "{} at line {line}, column {column}", self.error),
+			None => write!(f, "{} at {}", self.error, self.span),
+		}
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+/// Tells whether `name` is a word of letters and underscores starting with a
+/// letter, where digits may follow an underscore as in `a_1`, as read by the
+/// parser.
+fn is_word(name: &str) -> bool {
+	let mut chars = name.chars();
+	let mut subscript = false;
+	chars.next().is_some_and(char::is_alphabetic)
+		&& chars.all(|c| {
+			subscript |= c == '_';
+			c.is_alphabetic() || c == '_' || (subscript && c.is_ascii_digit())
+		})
+}
+
+/// Options of the expression parser.
+///
+/// By default the indeterminate is `X`, matched case-insensitively, and
+/// implicit multiplications such as `2X(X + 1)` are allowed. The same name can
+/// be used to print polynomials with `Polynomial::with_variable`.
+///
+/// # Example
+/// ```rust
+/// use polyx::{expr::ParserOptions, *};
+/// let options = ParserOptions::new().variable("omega").case_sensitive(true);
+/// let p = Polynomial::<i32>::parse_string_with("omega^2 - 2omega", &options).unwrap();
+/// assert_eq!(p, polynomial![0, -2, 1]);
+/// assert!(Polynomial::<i32>::parse_string_with("Omega", &options).is_err());
+/// assert!(p.with_variable(options.variable_name()).to_string().contains("omega^2"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserOptions {
+	variable: String,
+	case_sensitive: bool,
+	implicit_multiplication: bool,
+}
+
+impl Default for ParserOptions {
+	fn default() -> Self {
+		ParserOptions {
+			variable: "X".to_string(),
+			case_sensitive: false,
+			implicit_multiplication: true,
+		}
+	}
+}
+
+impl ParserOptions {
+	/// Returns the default options.
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the name of the indeterminate, a word of letters that may contain
+	/// underscores, e.g. `t`, `z` or `omega`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `name` is not such a word.
+	pub fn variable(mut self, name: &str) -> Self {
+		if !is_word(name) {
+			panic!("The name of the variable must be a word of letters, got {name:?}");
+		}
+		self.variable = name.to_string();
+		self
+	}
+
+	/// Sets whether the name of the indeterminate is case sensitive.
+	#[inline]
+	pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+		self.case_sensitive = case_sensitive;
+		self
+	}
+
+	/// Sets whether juxtaposed factors such as `2X` are multiplied, otherwise
+	/// an explicit `*` is required.
+	#[inline]
+	pub fn implicit_multiplication(mut self, implicit_multiplication: bool) -> Self {
+		self.implicit_multiplication = implicit_multiplication;
+		self
+	}
+
+	/// Returns the name of the indeterminate.
+	#[inline]
+	pub fn variable_name(&self) -> &str {
+		&self.variable
+	}
+
+	/// Returns whether juxtaposed factors are multiplied.
+	#[inline]
+	pub fn allows_implicit_multiplication(&self) -> bool {
+		self.implicit_multiplication
+	}
+
+	/// Returns the length in bytes of the name of the indeterminate if `word`
+	/// starts with it.
+	pub(crate) fn variable_prefix(&self, word: &str) -> Option<usize> {
+		let length = self.variable.chars().count();
+		let end = word
+			.char_indices()
+			.nth(length)
+			.map_or(word.len(), |(i, _)| i);
+		let prefix = &word[..end];
+		let matches = if self.case_sensitive {
+			prefix == self.variable
+		} else {
+			prefix.to_lowercase() == self.variable.to_lowercase()
+		};
+		matches.then_some(end)
+	}
+}
+
+/// Parses a polynomial expression without evaluating it.
+///
+/// Parentheses, calls, minus signs and exponents can be nested up to 128
+/// levels, beyond which a `NestingTooDeep` error is returned, so that deep
+/// inputs cannot overflow the stack.
+///
+/// # Example
+/// ```rust
+/// use polyx::expr::{parse_expr, BinaryOp, ExprKind, Span};
+/// let expr = parse_expr("2X + 1").unwrap();
+/// let ExprKind::Binary(BinaryOp::Add, lhs, _) = &expr.kind else { panic!() };
+/// assert_eq!(lhs.span, Span { start: 0, end: 2 });
+/// assert_eq!(parse_expr("X + ?").unwrap_err().span(), Span { start: 4, end: 5 });
+/// ```
+pub fn parse_expr(s: &str) -> Result<Expr, ParseError> {
+	parse_expr_with(s, &ParserOptions::default())
+}
+
+/// Parses a polynomial expression with the given options, see `parse_expr`.
+pub fn parse_expr_with(s: &str, options: &ParserOptions) -> Result<Expr, ParseError> {
+	Parser::new(s, options)?.parse()
+}
+
+/// A function of an `Environment`, with its number of arguments.
+#[derive(Clone)]
+struct Function<T> {
+	arity: usize,
+	#[allow(clippy::type_complexity)]
+	f: Rc<dyn Fn(&[Polynomial<T>]) -> Result<Polynomial<T>, String>>,
+}
+
+/// The values of the named parameters and functions of expressions, see
+/// `Expr::eval_in`.
+///
+/// Names are case sensitive words of letters and underscores. A word is only
+/// a name when it cannot be read as the indeterminate and imaginary units, so
+/// a parameter is separated from them by an operator or whitespace, as in
+/// `a X` rather than `aX`.
+///
+/// # Example
+/// ```rust
+/// use polyx::{expr::Environment, *};
+/// let environment: Environment<i32> = [("a", 2), ("b", -1), ("c", 3)].into_iter().collect();
+/// let p = Polynomial::parse_string_in("a*X^2 + b*X + c", &environment).unwrap();
+/// assert_eq!(p, polynomial![3, -1, 2]);
+///
+/// let environment = environment
+///     .polynomial("q", polynomial![1, 1])
+///     .function("double", 1, |args| Ok(args[0].clone() * polynomial![2]));
+/// let p = Polynomial::parse_string_in("double(q^2) - a", &environment).unwrap();
+/// assert_eq!(p, polynomial![0, 4, 2]);
+/// assert_eq!(
+///     Polynomial::parse_string_in("d X + e + a", &environment),
+///     Err("UnboundNames(d, e)".to_string())
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Environment<T> {
+	constants: HashMap<String, Polynomial<T>>,
+	functions: HashMap<String, Function<T>>,
+}
+
+impl<T> Default for Environment<T> {
+	fn default() -> Self {
+		Environment {
+			constants: HashMap::new(),
+			functions: HashMap::new(),
+		}
+	}
+}
+
+impl<T> fmt::Debug for Environment<T>
+where
+	T: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut functions: Vec<_> = self.functions.keys().collect();
+		functions.sort();
+		f.debug_struct("Environment")
+			.field("constants", &self.constants)
+			.field("functions", &functions)
+			.finish()
+	}
+}
+
+impl<T> Environment<T>
+where
+	T: CoefficientLiteral,
+{
+	/// Returns an environment without any name, in which only the built-in
+	/// functions are defined, see `Expr::eval_in`.
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Binds a name to a constant.
+	///
+	/// # Panics
+	///
+	/// Same as `polynomial`.
+	#[inline]
+	pub fn constant(self, name: &str, value: T) -> Self {
+		self.polynomial(name, polynomial![value])
+	}
+
+	/// Binds a name to a polynomial, which is substituted for the name.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `name` is not a word of letters and
+	/// underscores.
+	pub fn polynomial(mut self, name: &str, value: Polynomial<T>) -> Self {
+		if !is_word(name) {
+			panic!("A parameter must be named by a word of letters, got {name:?}");
+		}
+		self.constants.insert(name.to_string(), value);
+		self
+	}
+
+	/// Binds a name to a function of `arity` polynomials, which may fail with
+	/// an error message.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `name` is not a word of letters and
+	/// underscores.
+	pub fn function<F>(mut self, name: &str, arity: usize, f: F) -> Self
+	where
+		F: Fn(&[Polynomial<T>]) -> Result<Polynomial<T>, String> + 'static,
+	{
+		if !is_word(name) {
+			panic!("A function must be named by a word of letters, got {name:?}");
+		}
+		let f = Rc::new(f);
+		self.functions
+			.insert(name.to_string(), Function { arity, f });
+		self
+	}
+
+	/// Returns the value bound to a parameter.
+	#[inline]
+	pub fn get(&self, name: &str) -> Option<&Polynomial<T>> {
+		self.constants.get(name)
+	}
+
+	/// Tells whether a node naming a parameter or calling a function can be
+	/// evaluated in the environment.
+	fn binds(&self, e: &Expr) -> bool {
+		match &e.kind {
+			ExprKind::Name(name) => self.constants.contains_key(name),
+			ExprKind::Call(name, arguments) => {
+				self.functions.contains_key(name)
+					|| Builtin::from_name(name).is_some()
+					|| (arguments.len() == 1 && self.constants.contains_key(name))
+			}
+			_ => true,
+		}
+	}
+}
+
+impl<T, S> FromIterator<(S, T)> for Environment<T>
+where
+	T: CoefficientLiteral,
+	S: AsRef<str>,
+{
+	/// Binds each name to its constant, as with `constant`.
+	fn from_iter<I: IntoIterator<Item = (S, T)>>(iter: I) -> Self {
+		iter.into_iter()
+			.fold(Self::new(), |environment, (name, value)| {
+				environment.constant(name.as_ref(), value)
+			})
+	}
+}
+
+/// The functions of the expression language. A function or a constant of an
+/// `Environment` with the same name replaces them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Builtin {
+	/// `D(p)`, the derivative of `p`.
+	Derivative,
+	/// `compose(p, q)`, the polynomial `p(q)`.
+	Compose,
+	/// `chebyshev(n)`, the Chebyshev polynomial `T_n`.
+	Chebyshev,
+	/// `T_n(p)`, the Chebyshev polynomial `T_n` composed with `p`.
+	ChebyshevOf(usize),
+	/// `bernstein(m, i)`, the Bernstein polynomial of degree `m` and index
+	/// `i`.
+	Bernstein,
+	/// `expand(p)`, the expanded form of `p`, which is how every expression is
+	/// evaluated anyway.
+	Expand,
+	/// `gcd(p, q)`, see `CoefficientLiteral::division`.
+	Gcd,
+}
+
+impl Builtin {
+	fn from_name(name: &str) -> Option<Self> {
+		Some(match name {
+			"D" => Builtin::Derivative,
+			"compose" => Builtin::Compose,
+			"chebyshev" => Builtin::Chebyshev,
+			"bernstein" => Builtin::Bernstein,
+			"expand" => Builtin::Expand,
+			"gcd" => Builtin::Gcd,
+			_ => {
+				let n = name.strip_prefix("T_")?;
+				if !n.bytes().all(|b| b.is_ascii_digit()) {
+					return None;
+				}
+				Builtin::ChebyshevOf(n.parse().ok()?)
+			}
+		})
+	}
+
+	fn arity(&self) -> usize {
+		match self {
+			Builtin::Compose | Builtin::Bernstein | Builtin::Gcd => 2,
+			_ => 1,
+		}
+	}
+}
+
+/// Returns the constant coefficient of a polynomial, which may be zero.
+fn constant<T: CoefficientLiteral>(p: &Polynomial<T>) -> T {
+	p.0.first().cloned().unwrap_or_else(T::zero)
+}
+
+/// Builds a polynomial by only trimming its zero coefficients, and not the
+/// negligible ones, so that literals below `TOL` are kept as in
+/// `X^2 + 1e-12X`. Evaluations trim the polynomials once they are complete.
+fn untrimmed<T: CoefficientLiteral>(mut coefficients: Vec<T>) -> Polynomial<T> {
+	while coefficients.last().is_some_and(|c| c.is_zero()) {
+		coefficients.pop();
+	}
+	Polynomial(coefficients)
+}
+
+/// Combines the coefficients of two polynomials with `f`, as a checked sum or
+/// difference, see `untrimmed`. Returns `None` if a coefficient overflows.
+fn combine<T: CoefficientLiteral>(
+	p: &Polynomial<T>,
+	q: &Polynomial<T>,
+	f: impl Fn(&T, &T) -> Option<T>,
+) -> Option<Polynomial<T>> {
+	let zero = T::zero();
+	(0..p.0.len().max(q.0.len()))
+		.map(|k| f(p.0.get(k).unwrap_or(&zero), q.0.get(k).unwrap_or(&zero)))
+		.collect::<Option<_>>()
+		.map(untrimmed)
+}
+
+/// Multiplies two polynomials with checked arithmetic, by scaling the
+/// coefficients of one of them when the other is a constant, see `untrimmed`.
+/// Returns `None` if a coefficient overflows.
+fn product<T: CoefficientLiteral>(p: &Polynomial<T>, q: &Polynomial<T>) -> Option<Polynomial<T>> {
+	let scaled = |p: &Polynomial<T>, c: &T| {
+		p.0.iter()
+			.map(|x| x.checked_product(c))
+			.collect::<Option<_>>()
+			.map(untrimmed)
+	};
+	match (p.0.as_slice(), q.0.as_slice()) {
+		([c], _) => scaled(q, c),
+		(_, [c]) => scaled(p, c),
+		_ => p.checked_mul(q),
+	}
+}
+
+impl Expr {
+	/// Returns the binding power of the node, as used to print parentheses.
+	fn precedence(&self) -> u32 {
+		match &self.kind {
+			ExprKind::Binary(op, _, _) => op.precedence(),
+			ExprKind::Neg(_) => 3,
+			_ => 5,
+		}
+	}
+
+	fn error(&self, error: PolynomialError) -> ParseError {
+		ParseError::new(error, self.span)
+	}
+
+	/// Calls `f` on every node of the expression, parents first.
+	fn walk<'a>(&'a self, f: &mut impl FnMut(&'a Expr)) {
+		let mut stack = vec![self];
+		while let Some(e) = stack.pop() {
+			f(e);
+			match &e.kind {
+				ExprKind::Neg(e) | ExprKind::Group(e) => stack.push(e),
+				ExprKind::Binary(_, lhs, rhs) => stack.extend([&**rhs, &**lhs]),
+				ExprKind::Call(_, arguments) => stack.extend(arguments.iter().rev()),
+				_ => {}
+			}
+		}
+	}
+
+	/// Splits a chain of left associative operators, such as `a - b * c + d`,
+	/// into its leftmost operand and the following nodes with their operator
+	/// and right operand, so that long sums are not processed recursively. The
+	/// chain stops at powers and at the operands printed between parentheses.
+	fn chain(&self) -> (&Expr, Vec<(&Expr, BinaryOp, &Expr)>) {
+		let mut links: Vec<(&Expr, BinaryOp, &Expr)> = Vec::new();
+		let mut e = self;
+		while let ExprKind::Binary(op, lhs, rhs) = &e.kind {
+			if *op == BinaryOp::Pow
+				|| links
+					.last()
+					.is_some_and(|(parent, _, _)| e.precedence() < parent.precedence())
+			{
+				break;
+			}
+			links.push((e, *op, rhs));
+			e = lhs;
+		}
+		links.reverse();
+		(e, links)
+	}
+
+	/// Returns the names of the parameters and functions of the expression, in
+	/// the order of their first occurrence.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::expr::parse_expr;
+	/// let expr = parse_expr("a X^2 + f(b, X) + a").unwrap();
+	/// assert_eq!(expr.names(), ["a", "f", "b"]);
+	/// ```
+	pub fn names(&self) -> Vec<&str> {
+		let mut names = Vec::new();
+		self.walk(&mut |e| {
+			if let ExprKind::Name(name) | ExprKind::Call(name, _) = &e.kind {
+				if !names.contains(&name.as_str()) {
+					names.push(name.as_str());
+				}
+			}
+		});
+		names
+	}
+
+	fn eval_literal<T>(
+		&self,
+		negative: bool,
+		literal: &Literal,
+	) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let mut c = T::from_literal(literal.parse(negative).map_err(|e| self.error(e))?);
+		if literal.imaginary > 0 {
+			let i = T::imaginary_unit().ok_or_else(|| self.error(UnsupportedCharacter('i')))?;
+			for _ in 0..literal.imaginary {
+				c = c * i.clone();
+			}
+		}
+		Ok(untrimmed(vec![c]))
+	}
+
+	/// Evaluates the expression with integer arithmetic, if it only has
+	/// integer literals and no division, so that exponents are exact whatever
+	/// the coefficient type, e.g. in a finite field.
+	fn eval_exponent(&self) -> Option<i64> {
+		match &self.kind {
+			ExprKind::Literal(literal) if literal.imaginary == 0 => literal.parse(false).ok(),
+			ExprKind::Neg(e) => e.eval_exponent()?.checked_neg(),
+			ExprKind::Group(e) => e.eval_exponent(),
+			ExprKind::Binary(BinaryOp::Pow, lhs, rhs) => lhs
+				.eval_exponent()?
+				.checked_pow(u32::try_from(rhs.eval_exponent()?).ok()?),
+			ExprKind::Binary(..) => {
+				let (first, links) = self.chain();
+				links
+					.into_iter()
+					.try_fold(first.eval_exponent()?, |a, (_, op, rhs)| {
+						let b = rhs.eval_exponent()?;
+						match op {
+							BinaryOp::Add => a.checked_add(b),
+							BinaryOp::Sub => a.checked_sub(b),
+							BinaryOp::Mul => a.checked_mul(b),
+							BinaryOp::Rem => a.checked_rem_euclid(b),
+							BinaryOp::Div | BinaryOp::Pow => None,
+						}
+					})
+			}
+			_ => None,
+		}
+	}
+
+	/// Computes `p2 / p1`, which must be exact, or `p2 % p1`.
+	fn eval_division<T>(
+		&self,
+		op: BinaryOp,
+		p2: &Polynomial<T>,
+		p1: &Polynomial<T>,
+	) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let impossible = || self.error(ImpossibleDivision(format!("{p2:?}"), format!("{p1:?}")));
+		let division = T::division()
+			.filter(|_| !p1.is_zero())
+			.ok_or_else(impossible)?;
+		match op {
+			BinaryOp::Rem => Ok((division.remainder)(p2, p1)),
+			_ => (division.quotient)(p2, p1).ok_or_else(impossible),
+		}
+	}
+
+	fn eval_power<T>(
+		&self,
+		base: &Expr,
+		exponent: &Expr,
+		environment: &Environment<T>,
+	) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let base = base.evaluate(environment)?;
+		match exponent.eval_exponent() {
+			Some(n) if n >= 0 && (base.degree() > 0 || base.is_zero()) => {
+				let degree = usize::try_from(n)
+					.ok()
+					.and_then(|n| n.checked_mul(base.degree()));
+				if degree.is_none_or(|degree| degree > MAX_DEGREE) {
+					return Err(self.error(PowerOverflow(format!("{base:?}"), n.to_string())));
+				}
+				base.checked_powi(n.unsigned_abs())
+					.ok_or_else(|| self.error(PowerOverflow(format!("{base:?}"), n.to_string())))
+			}
+			Some(n) => {
+				if base.degree() > 0 || base.is_zero() {
+					return Err(self.error(ImpossiblePower(format!("{base:?}"), n.to_string())));
+				}
+				// Constants are raised with overflow checks, and their inverses must be
+				// exact like divisions
+				let power = constant(&base)
+					.checked_powi(n.unsigned_abs())
+					.ok_or_else(|| self.error(PowerOverflow(format!("{base:?}"), n.to_string())))?;
+				if n >= 0 {
+					Ok(polynomial![power])
+				} else {
+					self.eval_division(BinaryOp::Div, &polynomial![T::one()], &polynomial![power])
+				}
+			}
+			None => {
+				let exponent = exponent.evaluate(environment)?;
+				if exponent.degree() > 0 {
+					return Err(self.error(ImpossiblePower2Polynomials(
+						format!("{base:?}"),
+						format!("{exponent:?}"),
+					)));
+				}
+				let exponent = constant(&exponent);
+				constant(&base)
+					.pow_constant(&exponent)
+					.filter(|_| base.degree() == 0)
+					.map(|c| polynomial![c])
+					.ok_or_else(|| {
+						self.error(ImpossiblePower(
+							format!("{base:?}"),
+							format!("{exponent:?}"),
+						))
+					})
+			}
+		}
+	}
+
+	/// Evaluates the expression to a polynomial with coefficients of type `T`.
+	///
+	/// Exponents made of integer literals are computed exactly as integers, so
+	/// that `X^(p + 1)` has the expected degree over a field of characteristic
+	/// `p`. Other exponents are only allowed for constants, with
+	/// `CoefficientLiteral::pow_constant`. Integer powers of constants report
+	/// a `PowerOverflow` error instead of overflowing, and negative powers must
+	/// be exact divisions. So do powers of polynomials of a degree above 10000.
+	///
+	/// Sums, differences, products and powers are computed with the checked
+	/// arithmetic of `CoefficientLiteral`, e.g. `checked_product`, and report
+	/// `OperationOverflow`, `NegationOverflow` or `PowerOverflow` errors
+	/// instead of overflowing the coefficient type, as `(X + 1)^40` over
+	/// `i32`. Divisions use the arithmetic of the type.
+	///
+	/// Divisions must be exact, as in `(X^2 - 1) / (X - 1)`, and `%` or `mod`
+	/// gives the remainder of the Euclidean division, see
+	/// `CoefficientLiteral::division`. Over the integers, it is the
+	/// pseudo-remainder when the leading coefficient of the divisor is not 1
+	/// or -1, and the remainder of two constants is nonnegative.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{expr::parse_expr, *};
+	/// let expr = parse_expr("X / 2 + 0.25").unwrap();
+	/// assert_eq!(expr.eval::<f64>().unwrap(), polynomial![0.25, 0.5]);
+	/// assert!(expr.eval::<i32>().is_err());
+	/// ```
+	pub fn eval<T>(&self) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		self.eval_in(&Environment::new())
+	}
+
+	/// Evaluates the expression with the parameters and functions of an
+	/// environment, see `eval`.
+	///
+	/// Returns an `UnboundNames` error listing all the names that the
+	/// environment does not bind, located at the first of them.
+	///
+	/// The following functions are built in, unless the environment binds
+	/// their name:
+	///
+	/// * `D(p)`, the derivative of `p`;
+	/// * `compose(p, q)`, the polynomial `p(q)`;
+	/// * `chebyshev(n)` and `T_n(p)`, the Chebyshev polynomial `T_n` and its
+	///   composition with `p`, as in `T_3(2X - 1)`;
+	/// * `bernstein(m, i)`, see `Polynomial::bernstein`;
+	/// * `expand(p)`, the expanded form of `p`;
+	/// * `gcd(p, q)`, which is monic over a field and has a positive leading
+	///   coefficient over the integers.
+	///
+	/// The integers `n`, `m` and `i` are computed exactly, like exponents. The
+	/// degrees `n` and `m`, and those of the compositions, are at most 10000,
+	/// and the coefficients are computed with checked arithmetic except for
+	/// `gcd`, so that the functions report a `FunctionFailed` error instead of
+	/// overflowing.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{expr::{parse_expr, Environment}, *};
+	/// let expr = parse_expr("a*X^2 + b*X + c").unwrap();
+	/// let error = expr.eval::<i32>().unwrap_err();
+	/// assert_eq!(error.to_string(), "UnboundNames(a, b, c) at 0..1");
+	///
+	/// let environment = Environment::new().constant("a", 2).constant("b", -1).constant("c", 3);
+	/// assert_eq!(expr.eval_in(&environment).unwrap(), polynomial![3, -1, 2]);
+	/// ```
+	pub fn eval_in<T>(&self, environment: &Environment<T>) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let mut unbound: Vec<&Expr> = Vec::new();
+		self.walk(&mut |e| {
+			if !environment.binds(e) {
+				unbound.push(e);
+			}
+		});
+		if let Some(first) = unbound.first() {
+			let mut names: Vec<String> = Vec::new();
+			for e in &unbound {
+				if let ExprKind::Name(name) | ExprKind::Call(name, _) = &e.kind {
+					if !names.contains(name) {
+						names.push(name.clone());
+					}
+				}
+			}
+			return Err(first.error(UnboundNames(names)));
+		}
+		self.eval_trimmed(environment)
+	}
+
+	/// Evaluates a call whose name is bound in the environment.
+	fn eval_call<T>(
+		&self,
+		name: &str,
+		arguments: &[Expr],
+		environment: &Environment<T>,
+	) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let arity = |expected: usize| {
+			if arguments.len() == expected {
+				Ok(())
+			} else {
+				Err(self.error(WrongArity(name.to_string(), expected, arguments.len())))
+			}
+		};
+		if let Some(function) = environment.functions.get(name) {
+			arity(function.arity)?;
+			let values = Self::eval_arguments(arguments, environment)?;
+			return (function.f)(&values)
+				.map_err(|message| self.error(FunctionFailed(name.to_string(), message)));
+		}
+		if let Some(value) = environment.get(name) {
+			arity(1)?;
+			return self.checked(BinaryOp::Mul, value, &arguments[0].evaluate(environment)?);
+		}
+		let builtin = Builtin::from_name(name)
+			.ok_or_else(|| self.error(UnboundNames(vec![name.to_string()])))?;
+		arity(builtin.arity())?;
+		// The degrees and indices are computed exactly, like exponents
+		let values = match builtin {
+			Builtin::Chebyshev | Builtin::Bernstein => Vec::new(),
+			_ => Self::eval_arguments(arguments, environment)?,
+		};
+		self.apply_builtin(name, builtin, arguments, values)
+	}
+
+	/// Evaluates the arguments of a call, trimmed as complete polynomials.
+	fn eval_arguments<T>(
+		arguments: &[Expr],
+		environment: &Environment<T>,
+	) -> Result<Vec<Polynomial<T>>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let mut values = Vec::with_capacity(arguments.len());
+		for e in arguments {
+			values.push(e.eval_trimmed(environment)?);
+		}
+		Ok(values)
+	}
+
+	/// Applies a built-in function to the `values` of its arguments, except for
+	/// the integer arguments of `chebyshev(n)` and `bernstein(m, i)`. It is
+	/// kept apart from `eval_call` to save the stack of nested calls.
+	fn apply_builtin<T>(
+		&self,
+		name: &str,
+		builtin: Builtin,
+		arguments: &[Expr],
+		mut values: Vec<Polynomial<T>>,
+	) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let failed = |message: String| self.error(FunctionFailed(name.to_string(), message));
+		let index = |e: &Expr| {
+			e.eval_exponent()
+				.and_then(|n| usize::try_from(n).ok())
+				.ok_or_else(|| failed(format!("expected a nonnegative integer, got {e}")))
+		};
+		let degree = |n: usize| {
+			if n <= MAX_DEGREE {
+				Ok(n)
+			} else {
+				Err(failed(format!("the degree {n} exceeds {MAX_DEGREE}")))
+			}
+		};
+		let overflow = || failed("the coefficients overflow".to_string());
+		Ok(match builtin {
+			Builtin::Derivative => values[0].checked_derivative().ok_or_else(overflow)?,
+			Builtin::Compose => {
+				degree(values[0].degree().saturating_mul(values[1].degree()))?;
+				values[0].checked_compose(&values[1]).ok_or_else(overflow)?
+			}
+			Builtin::Chebyshev => Polynomial::checked_chebyshev(degree(index(&arguments[0])?)?)
+				.ok_or_else(overflow)?,
+			Builtin::ChebyshevOf(n) => {
+				degree(n.saturating_mul(values[0].degree()))?;
+				Polynomial::checked_chebyshev(degree(n)?)
+					.ok_or_else(overflow)?
+					.checked_compose(&values[0])
+					.ok_or_else(overflow)?
+			}
+			Builtin::Bernstein => {
+				let m = degree(index(&arguments[0])?)?;
+				Polynomial::checked_bernstein(m, index(&arguments[1])?).ok_or_else(overflow)?
+			}
+			Builtin::Expand => values.swap_remove(0),
+			Builtin::Gcd => {
+				let (p, q) = (&values[0], &values[1]);
+				let division = T::division().ok_or_else(|| {
+					failed(format!(
+						"{p:?} and {q:?} have no gcd over these coefficients"
+					))
+				})?;
+				(division.gcd)(p, q)
+			}
+		})
+	}
+
+	/// Evaluates the opposite of an expression by negating its leftmost
+	/// literal, as in a product `2147483648X`.
+	fn eval_negated<T>(&self, environment: &Environment<T>) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		match &self.kind {
+			ExprKind::Literal(literal) => self.eval_literal(true, literal),
+			ExprKind::Group(e) => e.eval_negated(environment),
+			ExprKind::Binary(BinaryOp::Mul, ..) => {
+				let mut factors = Vec::new();
+				let mut e = self;
+				while let ExprKind::Binary(BinaryOp::Mul, lhs, rhs) = &e.kind {
+					factors.push((e, rhs));
+					e = lhs;
+				}
+				let mut p = e.eval_negated(environment)?;
+				for (e, rhs) in factors.into_iter().rev() {
+					p = e.checked(BinaryOp::Mul, &p, &rhs.evaluate(environment)?)?;
+				}
+				Ok(p)
+			}
+			_ => self.negated(&self.evaluate(environment)?),
+		}
+	}
+
+	/// Evaluates an expression whose names are all bound, and trims its
+	/// negligible coefficients as for any other polynomial.
+	fn eval_trimmed<T>(&self, environment: &Environment<T>) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		self.evaluate(environment).map(|p| Polynomial::from(p.0))
+	}
+
+	/// Evaluates an expression whose names are all bound.
+	fn evaluate<T>(&self, environment: &Environment<T>) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		match &self.kind {
+			ExprKind::Literal(literal) => self.eval_literal(false, literal),
+			ExprKind::Variable(_) => Ok(polynomial![T::zero(), T::one()]),
+			ExprKind::Name(name) => environment
+				.get(name)
+				.cloned()
+				.ok_or_else(|| self.error(UnboundNames(vec![name.clone()]))),
+			ExprKind::Call(name, arguments) => self.eval_call(name, arguments, environment),
+			ExprKind::Group(e) => e.evaluate(environment),
+			ExprKind::Neg(e) => match &e.kind {
+				// Negated literals are parsed as such, e.g. for i32::MIN
+				ExprKind::Literal(literal) => self.eval_literal(true, literal),
+				_ => self.negated(&e.evaluate(environment)?),
+			},
+			ExprKind::Binary(BinaryOp::Pow, base, exponent) => {
+				self.eval_power(base, exponent, environment)
+			}
+			ExprKind::Binary(..) => {
+				let (first, links) = self.chain();
+				let mut p = first.evaluate(environment)?;
+				for (e, op, rhs) in links {
+					p = e.eval_binary(op, p, rhs, environment)?;
+				}
+				Ok(p)
+			}
+		}
+	}
+
+	/// Applies the operator of a node of a chain, other than a power, to the
+	/// value `p2` of its left operand and to its right operand `rhs`.
+	fn eval_binary<T>(
+		&self,
+		op: BinaryOp,
+		p2: Polynomial<T>,
+		rhs: &Expr,
+		environment: &Environment<T>,
+	) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let p1 = match rhs.evaluate(environment) {
+			// The literal may only fit once negated, e.g. `X - 2147483648` for i32
+			Err(e) if op == BinaryOp::Sub && matches!(e.error, LiteralOverflow(..)) => {
+				let p1 = rhs.eval_negated(environment).map_err(|_| e)?;
+				return self.checked(BinaryOp::Add, &p2, &p1);
+			}
+			p1 => p1?,
+		};
+		match op {
+			BinaryOp::Div | BinaryOp::Rem => {
+				self.eval_division(op, &Polynomial::from(p2.0), &Polynomial::from(p1.0))
+			}
+			_ => self.checked(op, &p2, &p1),
+		}
+	}
+
+	/// Computes the sum, the difference or the product `p2 op p1` with checked
+	/// arithmetic, and reports an overflow of the coefficients as an
+	/// `OperationOverflow` error.
+	fn checked<T>(
+		&self,
+		op: BinaryOp,
+		p2: &Polynomial<T>,
+		p1: &Polynomial<T>,
+	) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		match op {
+			BinaryOp::Add => combine(p2, p1, T::checked_sum),
+			BinaryOp::Sub => combine(p2, p1, T::checked_difference),
+			_ => product(p2, p1),
+		}
+		.ok_or_else(|| self.error(OperationOverflow(op, format!("{p2:?}"), format!("{p1:?}"))))
+	}
+
+	/// Computes `-p` with checked arithmetic, and reports an overflow of the
+	/// coefficients, e.g. of an unsigned type, as a `NegationOverflow` error.
+	fn negated<T>(&self, p: &Polynomial<T>) -> Result<Polynomial<T>, ParseError>
+	where
+		T: CoefficientLiteral,
+	{
+		let zero = T::zero();
+		p.0.iter()
+			.map(|c| zero.checked_difference(c))
+			.collect::<Option<_>>()
+			.map(untrimmed)
+			.ok_or_else(|| self.error(NegationOverflow(format!("{p:?}"))))
+	}
+}
+
+impl fmt::Display for Expr {
+	/// Prints the expression with explicit multiplications and the
+	/// parentheses needed to parse it back to the same tree.
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let operand = |f: &mut fmt::Formatter, e: &Expr, parenthesize: bool| {
+			if parenthesize {
+				write!(f, "({e})")
+			} else {
+				write!(f, "{e}")
+			}
+		};
+		match &self.kind {
+			ExprKind::Literal(literal) => write!(f, "{literal}"),
+			ExprKind::Variable(name) | ExprKind::Name(name) => write!(f, "{name}"),
+			ExprKind::Call(name, arguments) => {
+				write!(f, "{name}(")?;
+				for (k, e) in arguments.iter().enumerate() {
+					if k > 0 {
+						write!(f, ", ")?;
+					}
+					write!(f, "{e}")?;
+				}
+				write!(f, ")")
+			}
+			ExprKind::Group(e) => write!(f, "({e})"),
+			ExprKind::Neg(e) => {
+				write!(f, "-")?;
+				operand(f, e, e.precedence() < self.precedence())
+			}
+			ExprKind::Binary(BinaryOp::Pow, base, exponent) => {
+				operand(f, base, base.precedence() <= self.precedence())?;
+				write!(f, "^")?;
+				operand(f, exponent, exponent.precedence() < 3)
+			}
+			ExprKind::Binary(..) => {
+				let (first, links) = self.chain();
+				let parent = links.first().map_or(self, |(e, _, _)| e);
+				operand(f, first, first.precedence() < parent.precedence())?;
+				for (e, op, rhs) in links {
+					write!(f, " {} ", op.symbol())?;
+					operand(f, rhs, rhs.precedence() <= e.precedence())?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl Drop for Expr {
+	/// Drops the nodes one after the other, so that long chains of operators
+	/// do not overflow the stack.
+	fn drop(&mut self) {
+		if matches!(
+			self.kind,
+			ExprKind::Literal(_) | ExprKind::Variable(_) | ExprKind::Name(_)
+		) {
+			return;
+		}
+		let mut stack = vec![mem::replace(
+			&mut self.kind,
+			ExprKind::Variable(String::new()),
+		)];
+		let mut take = |e: &mut Expr| mem::replace(&mut e.kind, ExprKind::Variable(String::new()));
+		while let Some(kind) = stack.pop() {
+			match kind {
+				ExprKind::Neg(mut e) | ExprKind::Group(mut e) => stack.push(take(&mut e)),
+				ExprKind::Binary(_, mut lhs, mut rhs) => {
+					stack.push(take(&mut lhs));
+					stack.push(take(&mut rhs));
+				}
+				ExprKind::Call(_, mut arguments) => {
+					stack.extend(arguments.iter_mut().map(&mut take));
+				}
+				_ => {}
+			}
+		}
+	}
+}
@@ -0,0 +1,365 @@
+use num_traits::{Inv, One, Zero};
+
+use super::Fp;
+use crate::Polynomial;
+
+/// A small deterministic pseudo-random generator (SplitMix64), used to draw
+/// the random polynomials needed by the probabilistic splitting algorithms.
+struct SplitMix(u64);
+
+impl SplitMix {
+	fn next(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		z ^ (z >> 31)
+	}
+}
+
+/// Returns a basis of the kernel of the matrix `m`, which has `n` columns.
+fn nullspace<const P: u64>(mut m: Vec<Vec<Fp<P>>>, n: usize) -> Vec<Vec<Fp<P>>> {
+	let mut pivot_cols = Vec::new();
+	let mut rank = 0;
+	for col in 0..n {
+		let Some(pivot) = (rank..m.len()).find(|&i| !m[i][col].is_zero()) else {
+			continue;
+		};
+		m.swap(rank, pivot);
+		let inv = m[rank][col].inv();
+		for x in m[rank].iter_mut() {
+			*x = *x * inv;
+		}
+		for i in 0..m.len() {
+			if i != rank && !m[i][col].is_zero() {
+				let factor = m[i][col];
+				let pivot_row = m[rank].clone();
+				for (x, y) in m[i].iter_mut().zip(pivot_row) {
+					*x = *x - factor * y;
+				}
+			}
+		}
+		pivot_cols.push(col);
+		rank += 1;
+	}
+	(0..n)
+		.filter(|col| !pivot_cols.contains(col))
+		.map(|free| {
+			let mut v = vec![Fp::zero(); n];
+			v[free] = Fp::one();
+			for (row, &col) in pivot_cols.iter().enumerate() {
+				v[col] = -m[row][free];
+			}
+			v
+		})
+		.collect()
+}
+
+impl<const P: u64> Polynomial<Fp<P>> {
+	/// Returns the polynomial divided by its leading coefficient.
+	fn monic(&self) -> Self {
+		if self.is_zero() {
+			return Self::zero();
+		}
+		let lc_inv = self[self.degree()].inv();
+		self.0.iter().map(|&x| x * lc_inv).collect()
+	}
+
+	fn quotient(p1: &Self, p2: &Self) -> Self {
+		Self::euclidean_division_immutable_float(p1, p2).0
+	}
+
+	fn remainder(p1: &Self, p2: &Self) -> Self {
+		Self::euclidean_division_immutable_float(p1, p2).1
+	}
+
+	/// Computes `a^((P^d - 1) / 2)` modulo `modulus`, using
+	/// `(P^d - 1) / 2 = (1 + P + ... + P^(d-1)) (P - 1) / 2` so that the
+	/// exponents stay small.
+	fn pow_half_order(a: &Self, d: usize, modulus: &Self) -> Self {
+		let mut t = Self::remainder(a, modulus);
+		let mut acc = t.clone();
+		for _ in 1..d {
+			t = t.powmod_float(P, modulus);
+			acc = Self::remainder(&(&acc * &t), modulus);
+		}
+		acc.powmod_float((P - 1) / 2, modulus)
+	}
+
+	/// Replaces every factor `u` by `gcd(u, w)` and `u / gcd(u, w)` when this
+	/// splits it non-trivially.
+	fn split_factors(factors: Vec<Self>, w: &Self) -> Vec<Self> {
+		let mut result = Vec::with_capacity(factors.len() + 1);
+		for u in factors {
+			let g = Self::gcd_float_immutable(&u, w);
+			if g.degree() > 0 && g.degree() < u.degree() {
+				result.push(Self::quotient(&u, &g));
+				result.push(g);
+			} else {
+				result.push(u);
+			}
+		}
+		result
+	}
+
+	fn sort_factors(factors: &mut [Self]) {
+		factors.sort_by_key(|p| {
+			(
+				p.degree(),
+				p.0.iter().map(|x| x.value()).collect::<Vec<_>>(),
+			)
+		});
+	}
+
+	/// Computes the distinct-degree factorization of a square-free polynomial.
+	///
+	/// Returns the pairs `(g_d, d)` such that `g_d` is the monic product of all
+	/// the irreducible factors of degree `d`, omitting the trivial `g_d`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{fields::Fp, *};
+	/// type F = Fp<5>;
+	/// // (X + 1)(X + 2)(X^2 + 2)
+	/// let p: Polynomial<F> = [4, 1, 4, 3, 1].into_iter().map(F::from).collect();
+	/// let ddf = p.distinct_degree_factorization();
+	/// assert_eq!(ddf.len(), 2);
+	/// assert_eq!((ddf[0].0.degree(), ddf[0].1), (2, 1));
+	/// assert_eq!((ddf[1].0.degree(), ddf[1].1), (2, 2));
+	/// ```
+	pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+		#[allow(non_snake_case)]
+		let X = crate::polynomial![Fp::zero(), Fp::one()];
+		let mut f = self.monic();
+		let mut h = X.clone();
+		let mut result = Vec::new();
+		let mut d = 0;
+		while f.degree() >= 2 * (d + 1) {
+			d += 1;
+			h = h.powmod_float(P, &f);
+			let g = Self::gcd_float_immutable(&f, &(&h - &X));
+			if g.degree() > 0 {
+				f = Self::quotient(&f, &g);
+				h = Self::remainder(&h, &f);
+				result.push((g, d));
+			}
+		}
+		if f.degree() > 0 {
+			let d = f.degree();
+			result.push((f, d));
+		}
+		result
+	}
+
+	/// Splits a square-free polynomial whose irreducible factors all have
+	/// degree `d` with the Cantor-Zassenhaus algorithm. The factors are monic
+	/// and sorted by increasing coefficients.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the degree of the polynomial is not a
+	/// multiple of `d`, or if the polynomial is not square-free, since a
+	/// repeated factor can never be split off.
+	pub fn equal_degree_factorization(&self, d: usize) -> Vec<Self> {
+		let f = self.monic();
+		let n = f.degree();
+		if d == 0 || !n.is_multiple_of(d) {
+			panic!("Cannot split a polynomial of degree {n} into factors of degree {d}");
+		}
+		if Self::gcd_float_immutable(&f, &f.derivative()).degree() > 0 {
+			panic!("Cannot split a polynomial with a repeated factor");
+		}
+		let mut rng = SplitMix(n as u64);
+		let mut factors = vec![f.clone()];
+		while factors.len() < n / d {
+			let a: Self = (0..n).map(|_| Fp::new(rng.next())).collect();
+			if a.degree() == 0 {
+				continue;
+			}
+			let w = &Self::pow_half_order(&a, d, &f) - &Self::one();
+			factors = Self::split_factors(factors, &w);
+		}
+		Self::sort_factors(&mut factors);
+		factors
+	}
+
+	/// Factors a square-free polynomial into monic irreducible factors with
+	/// Berlekamp's algorithm. The number of factors is the dimension of the
+	/// Berlekamp subalgebra, and the factors are split by random elements of
+	/// that subalgebra. They are sorted by increasing degree.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{fields::Fp, *};
+	/// type F = Fp<5>;
+	/// // (X + 1)(X + 2)(X^2 + 2)
+	/// let p: Polynomial<F> = [4, 1, 4, 3, 1].into_iter().map(F::from).collect();
+	/// let factors = p.berlekamp_factorization();
+	/// assert_eq!(factors.len(), 3);
+	/// assert_eq!(factors.iter().fold(Polynomial::from(F::from(1)), |acc, g| acc * g), p);
+	/// ```
+	pub fn berlekamp_factorization(&self) -> Vec<Self> {
+		#[allow(non_snake_case)]
+		let X = crate::polynomial![Fp::zero(), Fp::one()];
+		let f = self.monic();
+		let n = f.degree();
+		if n <= 1 {
+			return if f.is_zero() { Vec::new() } else { vec![f] };
+		}
+		// Row i of the Berlekamp matrix holds the coefficients of X^(iP) mod f
+		let x_p = X.powmod_float(P, &f);
+		let mut q = vec![vec![Fp::zero(); n]; n];
+		let mut row = Self::one();
+		for (i, q_row) in q.iter_mut().enumerate() {
+			if i > 0 {
+				row = Self::remainder(&(&row * &x_p), &f);
+			}
+			for (j, c) in row.0.iter().enumerate() {
+				q_row[j] = *c;
+			}
+		}
+		// The subalgebra is the kernel of the transpose of Q - I
+		let m = (0..n)
+			.map(|j| {
+				(0..n)
+					.map(|i| if i == j { q[i][j] - Fp::one() } else { q[i][j] })
+					.collect()
+			})
+			.collect();
+		let basis = nullspace(m, n);
+
+		let mut rng = SplitMix(n as u64);
+		let mut factors = vec![f.clone()];
+		while factors.len() < basis.len() {
+			let mut v = vec![Fp::zero(); n];
+			for b in &basis {
+				let c = Fp::new(rng.next());
+				for (x, y) in v.iter_mut().zip(b) {
+					*x = *x + c * *y;
+				}
+			}
+			let w = &Self::from(v).powmod_float((P - 1) / 2, &f) - &Self::one();
+			factors = Self::split_factors(factors, &w);
+		}
+		Self::sort_factors(&mut factors);
+		factors
+	}
+
+	/// Tells whether the polynomial is irreducible, using Rabin's test: a
+	/// polynomial `f` of degree `n` is irreducible if and only if `f` divides
+	/// `X^(P^n) - X` and is coprime with `X^(P^(n/q)) - X` for every prime
+	/// divisor `q` of `n`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{fields::Fp, *};
+	/// type F = Fp<3>;
+	/// assert!(polynomial![F::from(1), F::from(0), F::from(1)].is_irreducible());
+	/// assert!(!polynomial![F::from(2), F::from(0), F::from(1)].is_irreducible());
+	/// ```
+	pub fn is_irreducible(&self) -> bool {
+		#[allow(non_snake_case)]
+		let X = crate::polynomial![Fp::zero(), Fp::one()];
+		let f = self.monic();
+		let n = f.degree();
+		if f.is_zero() || n == 0 {
+			return false;
+		}
+		if n == 1 {
+			return true;
+		}
+		let prime_divisors: Vec<usize> = (2..=n)
+			.filter(|&q| n.is_multiple_of(q) && (2..q).all(|r| q % r != 0))
+			.collect();
+		let mut h = X.clone();
+		for k in 1..=n {
+			h = h.powmod_float(P, &f);
+			if prime_divisors.iter().any(|&q| k == n / q)
+				&& Self::gcd_float_immutable(&f, &(&h - &X)).degree() > 0
+			{
+				return false;
+			}
+		}
+		h == X
+	}
+
+	/// Square-free decomposition in characteristic P: the p-th powers that
+	/// make the derivative vanish are handled by taking p-th roots.
+	fn square_free_decomposition_fp(&self) -> Vec<(Self, usize)> {
+		let f = self.monic();
+		if f.degree() == 0 {
+			return Vec::new();
+		}
+		let mut result = Vec::new();
+		let mut c = Self::gcd_float_immutable(&f, &f.derivative());
+		let mut w = Self::quotient(&f, &c);
+		let mut i = 1;
+		while w.degree() > 0 {
+			let y = Self::gcd_float_immutable(&w, &c);
+			let factor = Self::quotient(&w, &y);
+			if factor.degree() > 0 {
+				result.push((factor, i));
+			}
+			c = Self::quotient(&c, &y);
+			w = y;
+			i += 1;
+		}
+		if c.degree() > 0 {
+			// c is a polynomial in X^P, and a^P = a in Fp
+			let root: Self = c.0.iter().step_by(P as usize).cloned().collect();
+			for (g, m) in root.square_free_decomposition_fp() {
+				result.push((g, m * P as usize));
+			}
+		}
+		result
+	}
+
+	/// Computes the complete factorization of the polynomial into monic
+	/// irreducible factors with their multiplicities, so that the polynomial is
+	/// the product of the `g^m` up to its leading coefficient. The factors are
+	/// sorted by increasing degree.
+	///
+	/// The polynomial is first made square-free, then split with the
+	/// distinct-degree factorization and the Cantor-Zassenhaus algorithm.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{fields::Fp, *};
+	/// type F = Fp<7>;
+	/// // (X + 1)^7 (X^2 + 1)^2
+	/// let p = polynomial![F::from(1), F::from(1)].powi(7)
+	///   * polynomial![F::from(1), F::from(0), F::from(1)].powi(2);
+	/// assert_eq!(
+	///   p.factor(),
+	///   vec![
+	///     (polynomial![F::from(1), F::from(1)], 7),
+	///     (polynomial![F::from(1), F::from(0), F::from(1)], 2)
+	///   ]
+	/// );
+	/// ```
+	pub fn factor(&self) -> Vec<(Self, usize)> {
+		if self.is_zero() {
+			panic!("The zero polynomial cannot be factored");
+		}
+		let mut result = Vec::new();
+		for (g, m) in self.square_free_decomposition_fp() {
+			for (h, d) in g.distinct_degree_factorization() {
+				for factor in h.equal_degree_factorization(d) {
+					result.push((factor, m));
+				}
+			}
+		}
+		result.sort_by_key(|(p, m)| {
+			(
+				p.degree(),
+				p.0.iter().map(|x| x.value()).collect::<Vec<_>>(),
+				*m,
+			)
+		});
+		result
+	}
+}
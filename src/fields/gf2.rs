@@ -0,0 +1,604 @@
+use std::{
+	fmt,
+	ops::{
+		Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr,
+		ShrAssign, Sub, SubAssign,
+	},
+};
+
+use num_traits::{One, PrimInt, Zero};
+
+use crate::Polynomial;
+
+/// Number of words under which products use the schoolbook algorithm.
+const KARATSUBA_THRESHOLD: usize = 16;
+
+/// A polynomial in GF(2)\[X\], stored as a bit-packed vector of words.
+///
+/// The coefficient of `X^i` is the bit `i % 64` of the word `i / 64`, and the
+/// last word is never zero, so that the zero polynomial has no words. Addition
+/// and subtraction are both a XOR of the words, products use carry-less
+/// multiplications of words combined with Karatsuba's algorithm.
+///
+/// # Example
+/// ```rust
+/// use num_traits::Zero;
+/// use polyx::{fields::Gf2Polynomial, *};
+/// // (X + 1)^2 = X^2 + 1 in characteristic 2
+/// let p = Gf2Polynomial::from(polynomial![1, 1]);
+/// assert_eq!(&p * &p, Gf2Polynomial::from_exponents(&[2, 0]));
+/// assert_eq!(&p + &p, Gf2Polynomial::zero());
+/// assert_eq!(Gf2Polynomial::from(polynomial![3, -1, 2, 5]).to_string(), "X^3 + X + 1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Gf2Polynomial(Vec<u64>);
+
+/// Carry-less product of two words, returned as its (low, high) words.
+#[inline]
+fn clmul(a: u64, b: u64) -> (u64, u64) {
+	let (mut low, mut high) = (0, 0);
+	let mut bits = b;
+	while bits != 0 {
+		let i = bits.trailing_zeros();
+		low ^= a << i;
+		if i > 0 {
+			high ^= a >> (64 - i);
+		}
+		bits &= bits - 1;
+	}
+	(low, high)
+}
+
+/// Inserts a zero bit after each bit of `x`, which squares a polynomial of
+/// degree less than 32.
+#[inline]
+fn spread(x: u32) -> u64 {
+	let mut x = x as u64;
+	x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+	x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+	x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+	x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+	(x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+fn trim(v: &mut Vec<u64>) {
+	while v.last() == Some(&0) {
+		v.pop();
+	}
+}
+
+/// XORs `src` into the beginning of `dst`, which must be at least as long.
+#[inline]
+fn xor_into(dst: &mut [u64], src: &[u64]) {
+	for (x, y) in dst.iter_mut().zip(src) {
+		*x ^= y;
+	}
+}
+
+/// XORs `src` shifted by `shift` bits into `dst`. The bits going past the end
+/// of `dst` are dropped.
+fn xor_shifted(dst: &mut [u64], src: &[u64], shift: usize) {
+	let (words, bits) = (shift / 64, shift % 64);
+	for (j, &w) in src.iter().enumerate() {
+		dst[j + words] ^= w << bits;
+		if bits > 0 {
+			if let Some(next) = dst.get_mut(j + words + 1) {
+				*next ^= w >> (64 - bits);
+			}
+		}
+	}
+}
+
+fn schoolbook(a: &[u64], b: &[u64], result: &mut [u64]) {
+	for (i, &x) in a.iter().enumerate() {
+		for (j, &y) in b.iter().enumerate() {
+			let (low, high) = clmul(x, y);
+			result[i + j] ^= low;
+			result[i + j + 1] ^= high;
+		}
+	}
+}
+
+/// Karatsuba's algorithm on words: returns the `a.len() + b.len()` words of
+/// the product, without trimming.
+fn karatsuba(a: &[u64], b: &[u64]) -> Vec<u64> {
+	let mut result = vec![0; a.len() + b.len()];
+	let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+	if short.len() <= KARATSUBA_THRESHOLD {
+		schoolbook(short, long, &mut result);
+		return result;
+	}
+	let m = long.len() / 2;
+	if short.len() <= m {
+		// Unbalanced operands: multiply by chunks of the long one
+		for (i, chunk) in long.chunks(short.len()).enumerate() {
+			xor_into(&mut result[i * short.len()..], &karatsuba(short, chunk));
+		}
+		return result;
+	}
+	let (a0, a1) = a.split_at(m);
+	let (b0, b1) = b.split_at(m);
+	let z0 = karatsuba(a0, b0);
+	let z2 = karatsuba(a1, b1);
+	let mut sum_a = vec![0; m.max(a1.len())];
+	xor_into(&mut sum_a, a0);
+	xor_into(&mut sum_a, a1);
+	let mut sum_b = vec![0; m.max(b1.len())];
+	xor_into(&mut sum_b, b0);
+	xor_into(&mut sum_b, b1);
+	let z1 = karatsuba(&sum_a, &sum_b);
+	xor_into(&mut result, &z0);
+	xor_into(&mut result[2 * m..], &z2);
+	xor_into(&mut result[m..], &z1);
+	xor_into(&mut result[m..], &z0);
+	xor_into(&mut result[m..], &z2);
+	result
+}
+
+impl Gf2Polynomial {
+	/// Creates a polynomial from its little-endian words of coefficients.
+	#[inline]
+	pub fn from_words(mut words: Vec<u64>) -> Self {
+		trim(&mut words);
+		Gf2Polynomial(words)
+	}
+
+	/// Creates the sum of the `X^e` for `e` in `exponents`. Repeated exponents
+	/// cancel out.
+	pub fn from_exponents(exponents: &[usize]) -> Self {
+		let len = exponents.iter().map(|e| e / 64 + 1).max().unwrap_or(0);
+		let mut words = vec![0; len];
+		for e in exponents {
+			words[e / 64] ^= 1 << (e % 64);
+		}
+		Self::from_words(words)
+	}
+
+	/// Returns the little-endian words of coefficients, without trailing zero.
+	#[inline]
+	pub fn words(&self) -> &[u64] {
+		&self.0
+	}
+
+	/// Returns the degree of the polynomial, 0 for the zero polynomial.
+	#[inline]
+	pub fn degree(&self) -> usize {
+		match self.0.last() {
+			Some(w) => 64 * self.0.len() - 1 - w.leading_zeros() as usize,
+			None => 0,
+		}
+	}
+
+	/// Evaluates the polynomial at `x`, 1 being `true`.
+	#[inline]
+	pub fn eval(&self, x: bool) -> bool {
+		if x {
+			self.0.iter().fold(0, |acc, w| acc ^ w.count_ones()) & 1 == 1
+		} else {
+			self[0]
+		}
+	}
+
+	/// Computes the square of the polynomial, which only spreads its bits since
+	/// the cross terms cancel out in characteristic 2.
+	pub fn square(&self) -> Self {
+		Gf2Polynomial::from_words(
+			self.0
+				.iter()
+				.flat_map(|&w| [spread(w as u32), spread((w >> 32) as u32)])
+				.collect(),
+		)
+	}
+
+	/// Computes the quotient and the remainder of the euclidean division of `p1`
+	/// by `p2`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `p2` is zero.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::fields::Gf2Polynomial;
+	/// let a = Gf2Polynomial::from_exponents(&[5, 2, 0]);
+	/// let b = Gf2Polynomial::from_exponents(&[2, 1]);
+	/// let (q, r) = Gf2Polynomial::euclidean_division(&a, &b);
+	/// assert_eq!(&(&q * &b) + &r, a);
+	/// assert!(r.degree() < b.degree());
+	/// ```
+	pub fn euclidean_division(p1: &Self, p2: &Self) -> (Self, Self) {
+		if p2.is_zero() {
+			panic!("Division by the zero polynomial in GF(2)[X]");
+		}
+		let (d1, d2) = (p1.degree(), p2.degree());
+		if p1.is_zero() || d1 < d2 {
+			return (Self::zero(), p1.clone());
+		}
+		let mut r = p1.0.clone();
+		let mut q = vec![0; (d1 - d2) / 64 + 1];
+		for i in (d2..=d1).rev() {
+			if (r[i / 64] >> (i % 64)) & 1 == 1 {
+				q[(i - d2) / 64] |= 1 << ((i - d2) % 64);
+				xor_shifted(&mut r, &p2.0, i - d2);
+			}
+		}
+		(Self::from_words(q), Self::from_words(r))
+	}
+
+	/// Computes the monic gcd of two polynomials with Euclid's algorithm.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::fields::Gf2Polynomial;
+	/// // (X + 1)(X^2 + X + 1) and (X + 1)^2
+	/// let a = Gf2Polynomial::from_exponents(&[3, 0]);
+	/// let b = Gf2Polynomial::from_exponents(&[2, 0]);
+	/// assert_eq!(Gf2Polynomial::gcd(&a, &b), Gf2Polynomial::from_exponents(&[1, 0]));
+	/// ```
+	pub fn gcd(p1: &Self, p2: &Self) -> Self {
+		let (mut a, mut b) = (p1.clone(), p2.clone());
+		while !b.is_zero() {
+			let r = &a % &b;
+			a = b;
+			b = r;
+		}
+		a
+	}
+
+	/// Tells whether the polynomial is irreducible, using Rabin's test: a
+	/// polynomial `f` of degree `n` is irreducible if and only if `f` divides
+	/// `X^(2^n) - X` and is coprime with `X^(2^(n/q)) - X` for every prime
+	/// divisor `q` of `n`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::fields::Gf2Polynomial;
+	/// // The AES polynomial
+	/// assert!(Gf2Polynomial::from_exponents(&[8, 4, 3, 1, 0]).is_irreducible());
+	/// // (X + 1)^8
+	/// assert!(!Gf2Polynomial::from_exponents(&[8, 0]).is_irreducible());
+	/// ```
+	pub fn is_irreducible(&self) -> bool {
+		#[allow(non_snake_case)]
+		let X = Self::from_exponents(&[1]);
+		let n = self.degree();
+		if n == 0 {
+			return false;
+		}
+		if n == 1 {
+			return true;
+		}
+		let prime_divisors: Vec<usize> = (2..=n)
+			.filter(|&q| n.is_multiple_of(q) && (2..q).all(|r| q % r != 0))
+			.collect();
+		let mut h = X.clone();
+		for k in 1..=n {
+			h = &h.square() % self;
+			if prime_divisors.iter().any(|&q| k == n / q)
+				&& Self::gcd(self, &(&h + &X)).degree() > 0
+			{
+				return false;
+			}
+		}
+		h == X
+	}
+}
+
+impl<T> From<&Polynomial<T>> for Gf2Polynomial
+where
+	T: PrimInt,
+{
+	/// Reduces the coefficients modulo 2.
+	fn from(p: &Polynomial<T>) -> Self {
+		let mut words = vec![0; p.0.len().div_ceil(64)];
+		for (i, c) in p.0.iter().enumerate() {
+			if *c & T::one() == T::one() {
+				words[i / 64] |= 1 << (i % 64);
+			}
+		}
+		Self::from_words(words)
+	}
+}
+
+impl<T> From<Polynomial<T>> for Gf2Polynomial
+where
+	T: PrimInt,
+{
+	#[inline]
+	fn from(p: Polynomial<T>) -> Self {
+		Self::from(&p)
+	}
+}
+
+impl<T> From<&Gf2Polynomial> for Polynomial<T>
+where
+	T: Zero + One + Clone,
+{
+	/// Lifts the coefficients to 0 and 1.
+	fn from(p: &Gf2Polynomial) -> Self {
+		if p.is_zero() {
+			return Polynomial(Vec::new());
+		}
+		Polynomial(
+			(0..=p.degree())
+				.map(|i| if p[i] { T::one() } else { T::zero() })
+				.collect(),
+		)
+	}
+}
+
+impl Index<usize> for Gf2Polynomial {
+	type Output = bool;
+
+	/// Returns the coefficient of `X^index`, which is `false` past the degree.
+	#[inline]
+	fn index(&self, index: usize) -> &bool {
+		match self.0.get(index / 64) {
+			Some(w) if (w >> (index % 64)) & 1 == 1 => &true,
+			_ => &false,
+		}
+	}
+}
+
+impl Add<&Gf2Polynomial> for &Gf2Polynomial {
+	type Output = Gf2Polynomial;
+
+	#[inline]
+	fn add(self, other: &Gf2Polynomial) -> Gf2Polynomial {
+		let (short, long) = if self.0.len() <= other.0.len() {
+			(self, other)
+		} else {
+			(other, self)
+		};
+		let mut words = long.0.clone();
+		xor_into(&mut words, &short.0);
+		Gf2Polynomial::from_words(words)
+	}
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Sub<&Gf2Polynomial> for &Gf2Polynomial {
+	type Output = Gf2Polynomial;
+
+	/// Subtraction is the same as addition in characteristic 2.
+	#[inline]
+	fn sub(self, other: &Gf2Polynomial) -> Gf2Polynomial {
+		self + other
+	}
+}
+
+impl Mul<&Gf2Polynomial> for &Gf2Polynomial {
+	type Output = Gf2Polynomial;
+
+	#[inline]
+	fn mul(self, other: &Gf2Polynomial) -> Gf2Polynomial {
+		if self.is_zero() || other.is_zero() {
+			return Gf2Polynomial::zero();
+		}
+		Gf2Polynomial::from_words(karatsuba(&self.0, &other.0))
+	}
+}
+
+impl Div<&Gf2Polynomial> for &Gf2Polynomial {
+	type Output = Gf2Polynomial;
+
+	#[inline]
+	fn div(self, other: &Gf2Polynomial) -> Gf2Polynomial {
+		Gf2Polynomial::euclidean_division(self, other).0
+	}
+}
+
+impl Rem<&Gf2Polynomial> for &Gf2Polynomial {
+	type Output = Gf2Polynomial;
+
+	#[inline]
+	fn rem(self, other: &Gf2Polynomial) -> Gf2Polynomial {
+		Gf2Polynomial::euclidean_division(self, other).1
+	}
+}
+
+// Implements the operators on owned values and the assign operators from the
+// ones on references
+macro_rules! impl_op_gf2 {
+	($op:ident, $op_assign:ident, $method:ident, $method_assign:ident) => {
+		impl $op<Gf2Polynomial> for &Gf2Polynomial {
+			type Output = Gf2Polynomial;
+
+			#[inline]
+			fn $method(self, other: Gf2Polynomial) -> Gf2Polynomial {
+				self.$method(&other)
+			}
+		}
+
+		impl $op<&Gf2Polynomial> for Gf2Polynomial {
+			type Output = Gf2Polynomial;
+
+			#[inline]
+			fn $method(self, other: &Gf2Polynomial) -> Gf2Polynomial {
+				(&self).$method(other)
+			}
+		}
+
+		impl $op<Gf2Polynomial> for Gf2Polynomial {
+			type Output = Gf2Polynomial;
+
+			#[inline]
+			fn $method(self, other: Gf2Polynomial) -> Gf2Polynomial {
+				(&self).$method(&other)
+			}
+		}
+
+		impl $op_assign<&Gf2Polynomial> for Gf2Polynomial {
+			#[inline]
+			fn $method_assign(&mut self, other: &Gf2Polynomial) {
+				*self = (&*self).$method(other);
+			}
+		}
+
+		impl $op_assign<Gf2Polynomial> for Gf2Polynomial {
+			#[inline]
+			fn $method_assign(&mut self, other: Gf2Polynomial) {
+				*self = (&*self).$method(&other);
+			}
+		}
+	};
+}
+
+impl_op_gf2!(Add, AddAssign, add, add_assign);
+impl_op_gf2!(Sub, SubAssign, sub, sub_assign);
+impl_op_gf2!(Mul, MulAssign, mul, mul_assign);
+impl_op_gf2!(Div, DivAssign, div, div_assign);
+impl_op_gf2!(Rem, RemAssign, rem, rem_assign);
+
+/// Multiplies the polynomial by `X^rhs`.
+///
+/// # Examples
+///
+/// ```
+/// use polyx::fields::Gf2Polynomial;
+///
+/// let p = Gf2Polynomial::from_exponents(&[1, 0]);
+/// assert_eq!(&p << 70, Gf2Polynomial::from_exponents(&[71, 70]));
+/// ```
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<I> Shl<I> for &Gf2Polynomial
+where
+	I: PrimInt,
+{
+	type Output = Gf2Polynomial;
+
+	#[inline]
+	fn shl(self, rhs_: I) -> Self::Output {
+		let rhs = rhs_
+			.to_usize()
+			.unwrap_or_else(|| panic!("Tried to shift a polynomial by a negative integer"));
+		if self.is_zero() {
+			return Gf2Polynomial::zero();
+		}
+		let mut words = vec![0; self.0.len() + rhs.div_ceil(64)];
+		xor_shifted(&mut words, &self.0, rhs);
+		Gf2Polynomial::from_words(words)
+	}
+}
+
+impl<I> Shl<I> for Gf2Polynomial
+where
+	I: PrimInt,
+{
+	type Output = Gf2Polynomial;
+
+	#[inline]
+	fn shl(self, rhs_: I) -> Self::Output {
+		&self << rhs_
+	}
+}
+
+impl<I> ShlAssign<I> for Gf2Polynomial
+where
+	I: PrimInt,
+{
+	#[inline]
+	fn shl_assign(&mut self, rhs_: I) {
+		*self = &*self << rhs_;
+	}
+}
+
+/// Divides the polynomial by `X^rhs`, dropping the terms of lower degree.
+///
+/// # Examples
+///
+/// ```
+/// use polyx::fields::Gf2Polynomial;
+///
+/// let p = Gf2Polynomial::from_exponents(&[71, 3]);
+/// assert_eq!(p >> 3, Gf2Polynomial::from_exponents(&[68, 0]));
+/// ```
+impl<I> Shr<I> for &Gf2Polynomial
+where
+	I: PrimInt,
+{
+	type Output = Gf2Polynomial;
+
+	#[inline]
+	fn shr(self, rhs_: I) -> Self::Output {
+		let rhs = rhs_
+			.to_usize()
+			.unwrap_or_else(|| panic!("Tried to shift a polynomial by a negative integer"));
+		let (words, bits) = (rhs / 64, rhs % 64);
+		if words >= self.0.len() {
+			return Gf2Polynomial::zero();
+		}
+		let high = &self.0[words..];
+		Gf2Polynomial::from_words(
+			(0..high.len())
+				.map(|i| {
+					let next = match high.get(i + 1) {
+						Some(w) if bits > 0 => w << (64 - bits),
+						_ => 0,
+					};
+					(high[i] >> bits) | next
+				})
+				.collect(),
+		)
+	}
+}
+
+impl<I> Shr<I> for Gf2Polynomial
+where
+	I: PrimInt,
+{
+	type Output = Gf2Polynomial;
+
+	#[inline]
+	fn shr(self, rhs_: I) -> Self::Output {
+		&self >> rhs_
+	}
+}
+
+impl<I> ShrAssign<I> for Gf2Polynomial
+where
+	I: PrimInt,
+{
+	#[inline]
+	fn shr_assign(&mut self, rhs_: I) {
+		*self = &*self >> rhs_;
+	}
+}
+
+impl Zero for Gf2Polynomial {
+	#[inline]
+	fn zero() -> Self {
+		Gf2Polynomial(Vec::new())
+	}
+
+	#[inline]
+	fn is_zero(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+impl One for Gf2Polynomial {
+	#[inline]
+	fn one() -> Self {
+		Gf2Polynomial(vec![1])
+	}
+}
+
+impl fmt::Display for Gf2Polynomial {
+	/// Prints the non-zero terms by decreasing degree, e.g. `X^3 + X + 1`.
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.is_zero() {
+			return write!(f, "0");
+		}
+		let terms: Vec<String> = (0..=self.degree())
+			.rev()
+			.filter(|&i| self[i])
+			.map(|i| match i {
+				0 => "1".to_string(),
+				1 => "X".to_string(),
+				_ => format!("X^{i}"),
+			})
+			.collect();
+		write!(f, "{}", terms.join(" + "))
+	}
+}
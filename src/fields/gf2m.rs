@@ -0,0 +1,285 @@
+use std::{
+	fmt,
+	ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::{Inv, Num, One, Zero};
+
+use crate::traits::{CoefficientLiteral, Division, FloatLike, HasNorm, PolyxNum};
+
+/// An element of the binary extension field GF(2^m) = GF(2)\[X\] / (POLY).
+///
+/// `POLY` is the modulus written in binary, including its leading term `X^m`,
+/// e.g. `0x11d` for X^8 + X^4 + X^3 + X^2 + 1. It must be irreducible for the
+/// quotient to be a field, which is not checked here, see
+/// `Gf2Polynomial::is_irreducible`. Elements are stored as their residue,
+/// a polynomial of degree less than m packed in a word.
+///
+/// Like `Fp`, `Gf2m<POLY>` implements `FloatLike` so that polynomials over
+/// it can use the exact field algorithms.
+///
+/// # Example
+/// ```rust
+/// use polyx::fields::Gf256;
+/// let a = Gf256::new(0x53);
+/// assert_eq!(a + a, Gf256::new(0));
+/// assert_eq!(a * Gf256::new(0x8c), Gf256::new(1));
+/// assert_eq!(Gf256::new(2).pow(8), Gf256::new(0x1d));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gf2m<const POLY: u64>(u64);
+
+/// The field with 256 elements used by most byte-oriented codes.
+pub type Gf256 = Gf2m<0x11d>;
+
+impl<const POLY: u64> Gf2m<POLY> {
+	/// The modulus of the field, including its leading term.
+	pub const MODULUS: u64 = POLY;
+
+	/// The degree m of the extension.
+	pub const DEGREE: u32 = {
+		assert!(POLY > 1, "The modulus of Gf2m must have a positive degree");
+		63 - POLY.leading_zeros()
+	};
+
+	/// Returns the class of the polynomial whose coefficients are the bits of
+	/// `value` modulo `POLY`.
+	#[inline]
+	pub fn new(mut value: u64) -> Self {
+		let m = Self::DEGREE;
+		while value >> m != 0 {
+			let top = 63 - value.leading_zeros();
+			value ^= POLY << (top - m);
+		}
+		Gf2m(value)
+	}
+
+	/// Returns the residue of the element, whose bits are the coefficients of a
+	/// polynomial of degree less than m.
+	#[inline]
+	pub fn value(self) -> u64 {
+		self.0
+	}
+
+	/// Computes the `exp`-th power of the element by binary exponentiation.
+	pub fn pow(self, mut exp: u64) -> Self {
+		let mut base = self;
+		let mut result = Self::one();
+		while exp > 0 {
+			if exp & 1 == 1 {
+				result = result * base;
+			}
+			base = base * base;
+			exp >>= 1;
+		}
+		result
+	}
+
+	/// Returns the smallest element, by residue, that generates the
+	/// multiplicative group of the field.
+	///
+	/// The prime factors of 2^m - 1 are found by trial division, so this is
+	/// only meant for small fields.
+	///
+	/// # Panics
+	///
+	/// This function will panic if m is greater than 32.
+	pub fn primitive_element() -> Self {
+		if Self::DEGREE > 32 {
+			panic!(
+				"Finding a primitive element needs m <= 32, got m = {}",
+				Self::DEGREE
+			);
+		}
+		let order = (1u64 << Self::DEGREE) - 1;
+		let mut prime_factors = Vec::new();
+		let mut rest = order;
+		let mut q = 2;
+		while q * q <= rest {
+			if rest.is_multiple_of(q) {
+				prime_factors.push(q);
+				while rest.is_multiple_of(q) {
+					rest /= q;
+				}
+			}
+			q += 1;
+		}
+		if rest > 1 {
+			prime_factors.push(rest);
+		}
+		(1..=order)
+			.map(Self::new)
+			.find(|g| prime_factors.iter().all(|&q| !g.pow(order / q).is_one()))
+			.unwrap_or_else(|| panic!("No primitive element: {POLY:#x} is not irreducible"))
+	}
+}
+
+duplicate::duplicate! {
+	[int_type; [u8]; [u16]; [u32]; [u64]]
+	impl<const POLY: u64> From<int_type> for Gf2m<POLY> {
+		#[inline]
+		#[allow(clippy::unnecessary_cast)]
+		fn from(value: int_type) -> Self {
+			Self::new(value as u64)
+		}
+	}
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLY: u64> Add for Gf2m<POLY> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, other: Self) -> Self {
+		Gf2m(self.0 ^ other.0)
+	}
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLY: u64> Sub for Gf2m<POLY> {
+	type Output = Self;
+
+	/// Subtraction is the same as addition in characteristic 2.
+	#[inline]
+	fn sub(self, other: Self) -> Self {
+		Gf2m(self.0 ^ other.0)
+	}
+}
+
+impl<const POLY: u64> Neg for Gf2m<POLY> {
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self {
+		self
+	}
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLY: u64> Mul for Gf2m<POLY> {
+	type Output = Self;
+
+	/// Shift-and-add multiplication, reducing modulo `POLY` at each shift.
+	#[inline]
+	fn mul(self, other: Self) -> Self {
+		let m = Self::DEGREE;
+		let (mut a, mut b) = (self.0, other.0);
+		let mut result = 0;
+		while b != 0 {
+			if b & 1 == 1 {
+				result ^= a;
+			}
+			b >>= 1;
+			a <<= 1;
+			if (a >> m) & 1 == 1 {
+				a ^= POLY;
+			}
+		}
+		Gf2m(result)
+	}
+}
+
+impl<const POLY: u64> Inv for Gf2m<POLY> {
+	type Output = Self;
+
+	/// Inverts the element as its (2^m - 2)-th power.
+	#[inline]
+	fn inv(self) -> Self {
+		if self.is_zero() {
+			panic!("0 is not invertible in Gf2m<{POLY:#x}>");
+		}
+		self.pow((u64::MAX >> (64 - Self::DEGREE)) - 1)
+	}
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const POLY: u64> Div for Gf2m<POLY> {
+	type Output = Self;
+
+	#[inline]
+	fn div(self, other: Self) -> Self {
+		self * other.inv()
+	}
+}
+
+impl<const POLY: u64> Rem for Gf2m<POLY> {
+	type Output = Self;
+
+	/// The remainder of a division in a field is always zero.
+	#[inline]
+	fn rem(self, other: Self) -> Self {
+		if other.is_zero() {
+			panic!("Division by zero in Gf2m<{POLY:#x}>");
+		}
+		Self::zero()
+	}
+}
+
+impl<const POLY: u64> Zero for Gf2m<POLY> {
+	#[inline]
+	fn zero() -> Self {
+		Gf2m(0)
+	}
+
+	#[inline]
+	fn is_zero(&self) -> bool {
+		self.0 == 0
+	}
+}
+
+impl<const POLY: u64> One for Gf2m<POLY> {
+	#[inline]
+	fn one() -> Self {
+		Gf2m(1)
+	}
+}
+
+impl<const POLY: u64> Num for Gf2m<POLY> {
+	type FromStrRadixErr = std::num::ParseIntError;
+
+	/// Parses the residue in the given radix, e.g. `"11d"` in radix 16.
+	fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		Ok(Self::new(u64::from_str_radix(s, radix)?))
+	}
+}
+
+impl<const POLY: u64> HasNorm for Gf2m<POLY> {
+	/// Uses the trivial absolute value, which is 0 for 0 and 1 otherwise.
+	#[inline]
+	fn norm(&self) -> f64 {
+		if self.is_zero() {
+			0.
+		} else {
+			1.
+		}
+	}
+}
+
+impl<const POLY: u64> fmt::Display for Gf2m<POLY> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:#x}", self.0)
+	}
+}
+
+impl<const POLY: u64> fmt::Debug for Gf2m<POLY> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:#x}", self.0)
+	}
+}
+
+impl<const POLY: u64> PolyxNum for Gf2m<POLY> {}
+impl<const POLY: u64> FloatLike for Gf2m<POLY> {}
+
+impl<const POLY: u64> CoefficientLiteral for Gf2m<POLY> {
+	type Literal = Self;
+
+	#[inline]
+	fn from_literal(literal: Self) -> Self {
+		literal
+	}
+
+	#[inline]
+	fn division() -> Option<Division<Self>> {
+		Some(Division::field())
+	}
+}
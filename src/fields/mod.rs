@@ -0,0 +1,11 @@
+//! Finite fields that can be used as polynomial coefficients, and bit-packed
+//! polynomials over GF(2).
+
+mod factor;
+mod gf2;
+mod gf2m;
+mod prime;
+
+pub use gf2::Gf2Polynomial;
+pub use gf2m::{Gf256, Gf2m};
+pub use prime::Fp;
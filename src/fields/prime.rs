@@ -0,0 +1,252 @@
+use std::{
+	fmt,
+	ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num_traits::{Inv, Num, One, Zero};
+
+use crate::traits::{CoefficientLiteral, Division, FloatLike, HasNorm, PolyxNum};
+
+/// An element of the prime field ℤ/Pℤ.
+///
+/// Elements are stored in Montgomery form so that multiplications only need
+/// one 128-bit product and no division. `P` must be an odd prime smaller than
+/// 2^63, which is checked at compile time except for the primality.
+///
+/// Since every non-zero element is invertible, `Fp<P>` implements
+/// `FloatLike`, so that `Polynomial<Fp<P>>` can use `inverse_float`,
+/// `euclidean_division_float` and `gcd_float` with exact results.
+///
+/// # Example
+/// ```rust
+/// use polyx::fields::Fp;
+/// type F = Fp<7>;
+/// assert_eq!(F::new(3) * F::new(5), F::new(1));
+/// assert_eq!(F::new(2) / F::new(4), F::new(4));
+/// assert_eq!(F::from(-1).value(), 6);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Fp<const P: u64>(u64);
+
+impl<const P: u64> Fp<P> {
+	/// The characteristic of the field.
+	pub const MODULUS: u64 = P;
+
+	const MODULUS_CHECK: () = assert!(
+		P > 2 && P % 2 == 1 && P < (1 << 63),
+		"The modulus of Fp must be an odd prime smaller than 2^63"
+	);
+
+	/// -P^(-1) modulo 2^64, computed with Newton's iteration.
+	const N_PRIME: u64 = {
+		let mut inv = P;
+		let mut i = 0;
+		while i < 6 {
+			inv = inv.wrapping_mul(2u64.wrapping_sub(P.wrapping_mul(inv)));
+			i += 1;
+		}
+		inv.wrapping_neg()
+	};
+
+	/// 2^128 modulo P, used to convert integers to the Montgomery form.
+	const R2: u64 = {
+		let r = (1u128 << 64) % P as u128;
+		(r * r % P as u128) as u64
+	};
+
+	/// Montgomery reduction: returns `t / 2^64` modulo P for `t < P * 2^64`.
+	#[inline]
+	const fn redc(t: u128) -> u64 {
+		let m = (t as u64).wrapping_mul(Self::N_PRIME);
+		let t = ((t + m as u128 * P as u128) >> 64) as u64;
+		if t >= P {
+			t - P
+		} else {
+			t
+		}
+	}
+
+	/// Returns the class of `value` modulo P.
+	#[inline]
+	pub fn new(value: u64) -> Self {
+		#[allow(clippy::let_unit_value)]
+		let () = Self::MODULUS_CHECK;
+		Fp(Self::redc((value % P) as u128 * Self::R2 as u128))
+	}
+
+	/// Returns the representative of the element in `[0, P)`.
+	#[inline]
+	pub fn value(self) -> u64 {
+		Self::redc(self.0 as u128)
+	}
+
+	/// Computes the `exp`-th power of the element by binary exponentiation.
+	pub fn pow(self, mut exp: u64) -> Self {
+		let mut base = self;
+		let mut result = Self::one();
+		while exp > 0 {
+			if exp & 1 == 1 {
+				result = result * base;
+			}
+			base = base * base;
+			exp >>= 1;
+		}
+		result
+	}
+}
+
+duplicate::duplicate! {
+	[int_type; [u32]; [u64]; [i32]; [i64]]
+	impl<const P: u64> From<int_type> for Fp<P> {
+		#[inline]
+		fn from(value: int_type) -> Self {
+			Self::new((value as i128).rem_euclid(P as i128) as u64)
+		}
+	}
+}
+
+impl<const P: u64> Add for Fp<P> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, other: Self) -> Self {
+		let s = self.0 + other.0;
+		Fp(if s >= P { s - P } else { s })
+	}
+}
+
+impl<const P: u64> Sub for Fp<P> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, other: Self) -> Self {
+		Fp(if self.0 >= other.0 {
+			self.0 - other.0
+		} else {
+			self.0 + P - other.0
+		})
+	}
+}
+
+impl<const P: u64> Neg for Fp<P> {
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self {
+		Self::zero() - self
+	}
+}
+
+impl<const P: u64> Mul for Fp<P> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: Self) -> Self {
+		Fp(Self::redc(self.0 as u128 * other.0 as u128))
+	}
+}
+
+impl<const P: u64> Inv for Fp<P> {
+	type Output = Self;
+
+	/// Inverts the element with Fermat's little theorem.
+	#[inline]
+	fn inv(self) -> Self {
+		if self.is_zero() {
+			panic!("0 is not invertible in Fp<{P}>");
+		}
+		self.pow(P - 2)
+	}
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const P: u64> Div for Fp<P> {
+	type Output = Self;
+
+	#[inline]
+	fn div(self, other: Self) -> Self {
+		self * other.inv()
+	}
+}
+
+impl<const P: u64> Rem for Fp<P> {
+	type Output = Self;
+
+	/// The remainder of a division in a field is always zero.
+	#[inline]
+	fn rem(self, other: Self) -> Self {
+		if other.is_zero() {
+			panic!("Division by zero in Fp<{P}>");
+		}
+		Self::zero()
+	}
+}
+
+impl<const P: u64> Zero for Fp<P> {
+	#[inline]
+	fn zero() -> Self {
+		Fp(0)
+	}
+
+	#[inline]
+	fn is_zero(&self) -> bool {
+		self.0 == 0
+	}
+}
+
+impl<const P: u64> One for Fp<P> {
+	#[inline]
+	fn one() -> Self {
+		Self::new(1)
+	}
+}
+
+impl<const P: u64> Num for Fp<P> {
+	type FromStrRadixErr = std::num::ParseIntError;
+
+	fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		let value = i128::from_str_radix(s, radix)?;
+		Ok(Self::new(value.rem_euclid(P as i128) as u64))
+	}
+}
+
+impl<const P: u64> HasNorm for Fp<P> {
+	/// Uses the trivial absolute value, which is 0 for 0 and 1 otherwise.
+	#[inline]
+	fn norm(&self) -> f64 {
+		if self.is_zero() {
+			0.
+		} else {
+			1.
+		}
+	}
+}
+
+impl<const P: u64> fmt::Display for Fp<P> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.value())
+	}
+}
+
+impl<const P: u64> fmt::Debug for Fp<P> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.value())
+	}
+}
+
+impl<const P: u64> PolyxNum for Fp<P> {}
+impl<const P: u64> FloatLike for Fp<P> {}
+
+impl<const P: u64> CoefficientLiteral for Fp<P> {
+	type Literal = Self;
+
+	#[inline]
+	fn from_literal(literal: Self) -> Self {
+		literal
+	}
+
+	#[inline]
+	fn division() -> Option<Division<Self>> {
+		Some(Division::field())
+	}
+}
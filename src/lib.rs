@@ -11,10 +11,15 @@ mod ops;
 mod specific;
 mod util;
 
-mod complex_parser;
+pub use util::{Canonical, ScientificFormat, WithVariable};
+
+pub mod codes;
 pub mod consts;
-mod errors;
+pub mod errors;
+pub mod expr;
+pub mod fields;
 mod parser;
+pub mod roots;
 pub mod traits;
 
 #[cfg(test)]
@@ -0,0 +1,104 @@
+use std::ops::{Add, Mul};
+
+use num_traits::{One, Zero};
+
+use crate::{
+	traits::{CoefficientLiteral, HasNorm, PolyxNum},
+	Polynomial,
+};
+
+impl<T> Polynomial<T>
+where
+	T: Zero + One + Mul<T, Output = T> + Add<T, Output = T> + Clone + HasNorm,
+{
+	/// Returns the formal derivative of the polynomial.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let p = polynomial![1, 0, 2, 3];
+	/// assert_eq!(p.derivative(), polynomial![0, 4, 9]);
+	/// ```
+	pub fn derivative(&self) -> Self {
+		// The factors are built by repeated addition so that no conversion from usize is
+		// needed
+		let mut factor = T::zero();
+		self.0
+			.iter()
+			.skip(1)
+			.map(|c| {
+				factor = factor.clone() + T::one();
+				factor.clone() * c.clone()
+			})
+			.collect()
+	}
+}
+
+impl<T> Polynomial<T>
+where
+	T: PolyxNum,
+{
+	/// Returns the composition `self(q)`, computed with Horner's method.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// // X^2 + 1 composed with X - 1
+	/// let p = polynomial![1, 0, 1];
+	/// assert_eq!(p.compose(&polynomial![-1, 1]), polynomial![2, -2, 1]);
+	/// ```
+	pub fn compose(&self, q: &Self) -> Self {
+		self.0
+			.iter()
+			.rev()
+			.fold(Self::zero(), |acc, c| acc * q + Self::from(vec![c.clone()]))
+	}
+}
+
+impl<T> Polynomial<T>
+where
+	T: CoefficientLiteral,
+{
+	/// Same as `derivative`, but returns `None` instead of overflowing the
+	/// coefficient type, as used by `D(p)` in expressions.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// assert_eq!(polynomial![1i8, 0, 2, 3].checked_derivative(), Some(polynomial![0, 4, 9]));
+	/// assert_eq!(polynomial![1i8, 0, 0, 50].checked_derivative(), None);
+	/// ```
+	pub fn checked_derivative(&self) -> Option<Self> {
+		let mut factor = T::zero();
+		self.0
+			.iter()
+			.skip(1)
+			.map(|c| {
+				factor = factor.checked_sum(&T::one())?;
+				factor.checked_product(c)
+			})
+			.collect()
+	}
+
+	/// Same as `compose`, but returns `None` instead of overflowing the
+	/// coefficient type, as used by `compose(p, q)` in expressions, see
+	/// `checked_mul`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let p = polynomial![1i8, 0, 1];
+	/// assert_eq!(p.checked_compose(&polynomial![-1, 1]), Some(polynomial![2, -2, 1]));
+	/// assert_eq!(p.checked_compose(&polynomial![0, 12]), None);
+	/// ```
+	pub fn checked_compose(&self, q: &Self) -> Option<Self> {
+		self.0.iter().rev().try_fold(Self::zero(), |acc, c| {
+			let mut coefficients = acc.checked_mul(q)?.0;
+			match coefficients.first_mut() {
+				Some(first) => *first = first.checked_sum(c)?,
+				None => coefficients.push(c.clone()),
+			}
+			Some(Self::from(coefficients))
+		})
+	}
+}
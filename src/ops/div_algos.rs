@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use num_traits::{Signed, Zero};
 
 use crate::{
-	traits::{FloatLike, HasNorm},
+	traits::{FloatLike, HasNorm, IntegerLike},
 	Polynomial,
 };
 
@@ -27,7 +27,6 @@ where
 	/// ```
 	/// Be careful with this function, overflows can happen pretty easily (i32
 	/// might not be big enough).
-
 	pub fn inverse(u: &Self, modulus: usize) -> Self {
 		if !u[0].is_one() && !(-u[0].clone()).is_one() {
 			panic!(
@@ -206,3 +205,117 @@ where
 		(q, r)
 	}
 }
+
+impl<T> Polynomial<T>
+where
+	T: IntegerLike,
+{
+	/// Divides `p1` by `p2` when the division is known to be exact in ℤ\[X\],
+	/// whatever the leading coefficient of `p2`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `p2` is zero or if `p2` does not divide `p1`
+	/// in ℤ\[X\], see `checked_exact_division`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let a = polynomial![3, 5, 2];
+	/// let b = polynomial![3, 2];
+	/// assert_eq!(Polynomial::exact_division(&a, &b), polynomial![1, 1]);
+	/// ```
+	pub fn exact_division(p1: &Self, p2: &Self) -> Self {
+		if p2.is_zero() {
+			panic!("Polynomial division by zero");
+		}
+		Self::checked_exact_division(p1, p2)
+			.unwrap_or_else(|| panic!("The division of {p1:?} by {p2:?} is not exact"))
+	}
+
+	/// Divides `p1` by `p2` in ℤ\[X\], or returns `None` if `p2` is zero or
+	/// does not divide `p1`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let a = polynomial![3, 5, 2];
+	/// assert_eq!(Polynomial::checked_exact_division(&a, &polynomial![3, 2]), Some(polynomial![1, 1]));
+	/// assert_eq!(Polynomial::checked_exact_division(&a, &polynomial![1, 2]), None);
+	/// ```
+	pub fn checked_exact_division(p1: &Self, p2: &Self) -> Option<Self> {
+		if p2.is_zero() {
+			return None;
+		}
+		if p1.is_zero() {
+			return Some(Self::zero());
+		}
+		if p1.degree() < p2.degree() {
+			return None;
+		}
+		let m = p1.degree();
+		let n = p2.degree();
+		let lc = p2[n].clone();
+		let mut r = p1.0.clone();
+		let mut q = vec![T::zero(); m - n + 1];
+		for k in (0..=(m - n)).rev() {
+			let (quotient, remainder) = r[k + n].div_rem(&lc);
+			if !remainder.is_zero() {
+				return None;
+			}
+			for j in 0..=n {
+				r[k + j] = r[k + j].clone() - quotient.clone() * p2[j].clone();
+			}
+			q[k] = quotient;
+		}
+		r.iter().all(|x| x.is_zero()).then(|| Self::from(q))
+	}
+
+	/// Performs the pseudo-division of `p1` by `p2` in ℤ\[X\], whatever the
+	/// leading coefficient `lc` of `p2`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `p2` is zero.
+	///
+	/// # Returns
+	///
+	/// The pseudo-quotient `q` and pseudo-remainder `r`, such that
+	/// `lc^(m - n + 1) p1 = q p2 + r` with `deg(r) < n`, where `m` and `n` are the
+	/// degrees of `p1` and `p2`. They are `0` and `p1` when `m < n`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let a = polynomial![1, 0, 1];
+	/// let b = polynomial![1, 2];
+	/// let (q, r) = Polynomial::pseudo_division(&a, &b);
+	///
+	/// assert_eq!((&q, &r), (&polynomial![-1, 2], &polynomial![5]));
+	/// assert_eq!(b * q + r, a * polynomial![4]);
+	/// ```
+	pub fn pseudo_division(p1: &Self, p2: &Self) -> (Self, Self) {
+		if p2.is_zero() {
+			panic!("Polynomial division by zero");
+		}
+		let n = p2.degree();
+		if p1.is_zero() || p1.degree() < n {
+			return (Self::zero(), p1.clone());
+		}
+		let m = p1.degree();
+		let lc = p2[n].clone();
+		let mut r = p1.0.clone();
+		let mut q = vec![T::zero(); m - n + 1];
+		for k in (0..=(m - n)).rev() {
+			let lead = r[k + n].clone();
+			for x in r.iter_mut().chain(q.iter_mut()) {
+				*x = x.clone() * lc.clone();
+			}
+			for j in 0..=n {
+				r[k + j] = r[k + j].clone() - lead.clone() * p2[j].clone();
+			}
+			q[k] = lead;
+		}
+		(Self::from(q), Self::from(r))
+	}
+}
@@ -1,7 +1,7 @@
 use num_traits::Zero;
 
 use crate::{
-	traits::{FloatLike, SignedIntLike},
+	traits::{FloatLike, IntegerLike, SignedIntLike},
 	Polynomial,
 };
 
@@ -166,3 +166,78 @@ where
 		Self::cofactor_float(&mut p1.clone(), &mut p2.clone())
 	}
 }
+
+impl<T> Polynomial<T>
+where
+	T: IntegerLike,
+{
+	/// Returns the content of the polynomial, i.e. the non-negative gcd of its
+	/// coefficients. The content of the zero polynomial is 0.
+	pub fn content(&self) -> T {
+		self.0.iter().fold(T::zero(), |g, c| g.gcd(c))
+	}
+
+	/// Returns the polynomial divided by its content, with a positive leading
+	/// coefficient.
+	/// Example:
+	/// ```rust
+	/// use polyx::*;
+	/// let p = polynomial![4, -2, -6];
+	/// assert_eq!(p.primitive_part(), polynomial![-2, 1, 3]);
+	/// ```
+	pub fn primitive_part(&self) -> Self {
+		if self.is_zero() {
+			return Self::zero();
+		}
+		let mut c = self.content();
+		if self[self.degree()].is_negative() {
+			c = -c;
+		}
+		self.0.iter().map(|x| x.clone() / c.clone()).collect()
+	}
+
+	/// Input : Two polynomials p1 and p2
+	/// Output : gcd(p1, p2) in ℤ\[X\], with a positive leading coefficient
+	///
+	/// Contrary to `gcd`, this never fails: it uses the primitive polynomial
+	/// remainder sequence, where every pseudo-remainder is divided by its
+	/// content to keep the coefficients small. They can still overflow with
+	/// fixed-size integers, in which case `BigInt` coefficients should be used.
+	/// Example:
+	/// ```rust
+	/// use polyx::*;
+	/// let p1 = polynomial![-2, 0, 2]; // 2(X - 1)(X + 1)
+	/// let p2 = polynomial![-6, 2, 4]; // 2(2X + 3)(X - 1)
+	/// assert_eq!(Polynomial::gcd_prs(&p1, &p2), polynomial![-2, 2]);
+	/// ```
+	pub fn gcd_prs(p1: &Self, p2: &Self) -> Self {
+		let c = p1.content().gcd(&p2.content());
+		let (mut a, mut b) = (p1.primitive_part(), p2.primitive_part());
+		if a.degree() < b.degree() {
+			std::mem::swap(&mut a, &mut b);
+		}
+		while !b.is_zero() {
+			let r = Self::pseudo_remainder_primitive(&a, &b);
+			a = b;
+			b = r;
+		}
+		a.into_iter().map(|x| x * c.clone()).collect()
+	}
+
+	/// Returns the primitive part of the pseudo-remainder of `a` by `b`, which is
+	/// assumed to be non-zero.
+	fn pseudo_remainder_primitive(a: &Self, b: &Self) -> Self {
+		let lb = b[b.degree()].clone();
+		let mut r = a.clone();
+		while !r.is_zero() && r.degree() >= b.degree() {
+			// Only scale by the cofactors of the leading coefficients
+			let g = lb.gcd(&r[r.degree()]);
+			let (sb, sr) = (lb.clone() / g.clone(), r[r.degree()].clone() / g);
+			let shift = r.degree() - b.degree();
+			let scaled_r: Self = r.0.iter().map(|x| x.clone() * sb.clone()).collect();
+			let scaled_b: Self = b.0.iter().map(|x| x.clone() * sr.clone()).collect();
+			r = (scaled_r - (scaled_b << shift)).primitive_part();
+		}
+		r.primitive_part()
+	}
+}
@@ -0,0 +1,487 @@
+use num::{BigInt, Integer, One, Signed, ToPrimitive, Zero};
+
+use crate::{fields::Fp, polynomial, traits::IntegerLike, Polynomial};
+
+// The Hensel lifting and the recombination run on big integers, whatever the
+// coefficient type, to avoid overflows.
+type BigPolynomial = Polynomial<BigInt>;
+
+/// Reduces every coefficient in `[0, m)`.
+fn reduce(p: &BigPolynomial, m: &BigInt) -> BigPolynomial {
+	p.0.iter().map(|c| c.mod_floor(m)).collect()
+}
+
+/// Reduces every coefficient in `(-m/2, m/2]`.
+fn symmetric(p: &BigPolynomial, m: &BigInt) -> BigPolynomial {
+	let half: BigInt = m >> 1;
+	p.0.iter()
+		.map(|c| {
+			let c = c.mod_floor(m);
+			if c > half {
+				c - m
+			} else {
+				c
+			}
+		})
+		.collect()
+}
+
+fn mod_inverse(a: &BigInt, m: &BigInt) -> BigInt {
+	let e = a.mod_floor(m).extended_gcd(m);
+	if !e.gcd.is_one() {
+		panic!("{a} is not invertible modulo {m}");
+	}
+	e.x.mod_floor(m)
+}
+
+/// Euclidean division modulo `m`, where the leading coefficient of `b` must be
+/// invertible modulo `m`.
+fn divrem_mod(a: &BigPolynomial, b: &BigPolynomial, m: &BigInt) -> (BigPolynomial, BigPolynomial) {
+	let mut r = reduce(a, m).0;
+	let n = b.degree();
+	if r.len() <= n {
+		return (Polynomial::zero(), Polynomial::from(r));
+	}
+	let lc_inv = mod_inverse(&b[n], m);
+	let mut q = vec![BigInt::zero(); r.len() - n];
+	for k in (0..q.len()).rev() {
+		let c = (&r[k + n] * &lc_inv).mod_floor(m);
+		for (j, y) in b.0.iter().enumerate() {
+			r[k + j] = (&r[k + j] - &c * y).mod_floor(m);
+		}
+		q[k] = c;
+	}
+	(Polynomial::from(q), Polynomial::from(r))
+}
+
+/// Returns `(s, t)` such that `s a + t b = 1` modulo the prime `p`, for coprime
+/// `a` and `b`.
+fn bezout_mod(a: &BigPolynomial, b: &BigPolynomial, p: &BigInt) -> (BigPolynomial, BigPolynomial) {
+	let (mut r0, mut r1) = (reduce(a, p), reduce(b, p));
+	let (mut s0, mut s1) = (polynomial![BigInt::one()], Polynomial::zero());
+	let (mut t0, mut t1) = (Polynomial::zero(), polynomial![BigInt::one()]);
+	while !r1.is_zero() {
+		let (q, r) = divrem_mod(&r0, &r1, p);
+		let s = reduce(&(&s0 - &q * &s1), p);
+		let t = reduce(&(&t0 - &q * &t1), p);
+		(r0, r1) = (r1, r);
+		(s0, s1) = (s1, s);
+		(t0, t1) = (t1, t);
+	}
+	let c_inv = polynomial![mod_inverse(&r0[0], p)];
+	(reduce(&(s0 * &c_inv), p), reduce(&(t0 * &c_inv), p))
+}
+
+/// Lifts a factorization `g = u0 v0` modulo `p`, with `u0` monic and
+/// `s u0 + t v0 = 1` modulo `p`, to a factorization `g = u v` modulo `p^k`.
+fn hensel_lift_pair(
+	g: &BigPolynomial,
+	(u0, v0): (&BigPolynomial, &BigPolynomial),
+	(s, t): (&BigPolynomial, &BigPolynomial),
+	p: &BigInt,
+	k: u32,
+) -> (BigPolynomial, BigPolynomial) {
+	let (mut u, mut v) = (u0.clone(), v0.clone());
+	let mut pj = p.clone();
+	for _ in 1..k {
+		let e: BigPolynomial = (g - &u * &v).0.iter().map(|c| c / &pj).collect();
+		let e = reduce(&e, p);
+		let (q, du) = divrem_mod(&(&e * t), u0, p);
+		let dv = reduce(&(&e * s + &q * v0), p);
+		let scale = polynomial![pj.clone()];
+		u = &u + &du * &scale;
+		v = &v + &dv * &scale;
+		pj *= p;
+	}
+	(u, v)
+}
+
+/// Lifts the factorization of `g` into the monic factors `factors` modulo `p`
+/// to a factorization modulo `p^k`, factor by factor.
+fn hensel_lift(
+	g: &BigPolynomial,
+	factors: &[BigPolynomial],
+	p: &BigInt,
+	k: u32,
+) -> Vec<BigPolynomial> {
+	let m = p.pow(k);
+	let lc = g[g.degree()].clone();
+	let mut current = g.clone();
+	let mut lifted = Vec::with_capacity(factors.len());
+	for i in 0..(factors.len() - 1) {
+		let v0 = factors[(i + 1)..]
+			.iter()
+			.fold(polynomial![lc.clone()], |acc, f| reduce(&(acc * f), p));
+		let (s, t) = bezout_mod(&factors[i], &v0, p);
+		let (u, v) = hensel_lift_pair(&current, (&factors[i], &v0), (&s, &t), p, k);
+		lifted.push(u);
+		current = v;
+	}
+	lifted.push(reduce(&(current * polynomial![mod_inverse(&lc, &m)]), &m));
+	lifted
+}
+
+/// Returns all the subsets of `0..n` with `size` elements, in lexicographic
+/// order.
+fn combinations(n: usize, size: usize) -> Vec<Vec<usize>> {
+	let mut result = Vec::new();
+	let mut current: Vec<usize> = (0..size).collect();
+	if size > n {
+		return result;
+	}
+	loop {
+		result.push(current.clone());
+		let Some(i) = (0..size).rev().find(|&i| current[i] < n - size + i) else {
+			return result;
+		};
+		current[i] += 1;
+		for j in (i + 1)..size {
+			current[j] = current[j - 1] + 1;
+		}
+	}
+}
+
+/// Zassenhaus recombination: tries the products of the lifted factors modulo
+/// `m`, by increasing number of factors, as candidate divisors of `g`.
+fn zassenhaus_recombination(
+	mut g: BigPolynomial,
+	mut lifted: Vec<BigPolynomial>,
+	m: &BigInt,
+) -> Vec<BigPolynomial> {
+	let mut result = Vec::new();
+	let mut size = 1;
+	'sizes: while 2 * size <= lifted.len() {
+		for subset in combinations(lifted.len(), size) {
+			let lc = g[g.degree()].clone();
+			let candidate = subset
+				.iter()
+				.fold(polynomial![lc], |acc, &i| reduce(&(acc * &lifted[i]), m));
+			let candidate = symmetric(&candidate, m).primitive_part();
+			if let Some(q) = Polynomial::checked_exact_division(&g, &candidate) {
+				result.push(candidate);
+				g = q;
+				for &i in subset.iter().rev() {
+					lifted.remove(i);
+				}
+				continue 'sizes;
+			}
+		}
+		size += 1;
+	}
+	result.push(g.primitive_part());
+	result
+}
+
+fn dot(a: &[BigInt], b: &[BigInt]) -> BigInt {
+	a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Subtracts from the vector `k` of the basis the multiple of the vector
+/// `l < k` that makes `|μ_kl| <= 1/2`, see `lll`.
+fn size_reduce(
+	basis: &mut [Vec<BigInt>],
+	lambda: &mut [Vec<BigInt>],
+	d: &[BigInt],
+	k: usize,
+	l: usize,
+) {
+	if (&lambda[k][l] * 2u32).abs() <= d[l + 1] {
+		return;
+	}
+	let q = (&lambda[k][l] * 2u32 + &d[l + 1]).div_floor(&(&d[l + 1] * 2u32));
+	let b_l = basis[l].clone();
+	for (x, y) in basis[k].iter_mut().zip(&b_l) {
+		*x -= &q * y;
+	}
+	lambda[k][l] -= &q * &d[l + 1];
+	let (head, tail) = lambda.split_at_mut(k);
+	for (x, y) in tail[0][..l].iter_mut().zip(&head[l][..l]) {
+		*x -= &q * y;
+	}
+}
+
+/// Reduces the lattice basis in place with the LLL algorithm (with
+/// δ = 3/4), using the integral version of Cohen's Algorithm 2.6.7. The
+/// Gram-Schmidt coefficients are kept as the integers
+/// `lambda[i][j] = μ_ij d[j + 1]`, where `d[j + 1]` is the Gram determinant of
+/// the first `j + 1` vectors. They are updated after each size reduction and
+/// each swap instead of being recomputed. The basis must be linearly
+/// independent.
+fn lll(basis: &mut [Vec<BigInt>]) {
+	let n = basis.len();
+	if n < 2 {
+		return;
+	}
+	let mut lambda = vec![vec![BigInt::zero(); n]; n];
+	let mut d = vec![BigInt::zero(); n + 1];
+	d[0] = BigInt::one();
+	d[1] = dot(&basis[0], &basis[0]);
+	let (mut k, mut k_max) = (1, 0);
+	while k < n {
+		if k > k_max {
+			k_max = k;
+			for j in 0..=k {
+				let mut u = dot(&basis[k], &basis[j]);
+				for i in 0..j {
+					u = (&d[i + 1] * u - &lambda[k][i] * &lambda[j][i]) / &d[i];
+				}
+				if j < k {
+					lambda[k][j] = u;
+				} else {
+					d[k + 1] = u;
+				}
+			}
+		}
+		size_reduce(basis, &mut lambda, &d, k, k - 1);
+		let l = &lambda[k][k - 1];
+		if &d[k + 1] * &d[k - 1] * 4u32 < &d[k] * &d[k] * 3u32 - l * l * 4u32 {
+			basis.swap(k, k - 1);
+			let (head, tail) = lambda.split_at_mut(k);
+			head[k - 1][..(k - 1)].swap_with_slice(&mut tail[0][..(k - 1)]);
+			let l = lambda[k][k - 1].clone();
+			let b = (&d[k - 1] * &d[k + 1] + &l * &l) / &d[k];
+			for row in &mut lambda[(k + 1)..=k_max] {
+				let t = row[k].clone();
+				row[k] = (&d[k + 1] * &row[k - 1] - &l * &t) / &d[k];
+				row[k - 1] = (&b * t + &l * &row[k]) / &d[k + 1];
+			}
+			d[k] = b;
+			k = (k - 1).max(1);
+		} else {
+			for l in (0..(k - 1)).rev() {
+				size_reduce(basis, &mut lambda, &d, k, l);
+			}
+			k += 1;
+		}
+	}
+}
+
+/// Lattice-based recombination: for a lifted factor `u` of degree `d`, the
+/// irreducible factor of `g` that `u` divides modulo `p` is the shortest
+/// vector of the lattice spanned by the `u X^i` and the `p^k X^j`, as long
+/// as `p^k` is large enough. It is found with LLL for increasing dimensions.
+fn lll_recombination(
+	mut g: BigPolynomial,
+	mut lifted: Vec<BigPolynomial>,
+	p: &BigInt,
+	m: &BigInt,
+) -> Vec<BigPolynomial> {
+	let mut result = Vec::new();
+	while lifted.len() > 1 {
+		let u = lifted.iter().max_by_key(|u| u.degree()).unwrap();
+		let d = u.degree();
+		let n = g.degree();
+		let u_mod_p = reduce(u, p);
+		let mut factor = None;
+		for j in (d + 1)..=n {
+			let mut basis: Vec<Vec<BigInt>> = (0..(j - d))
+				.map(|i| {
+					let mut v = vec![BigInt::zero(); j];
+					v[i..(i + d + 1)].clone_from_slice(&u.0);
+					v
+				})
+				.chain((0..d).map(|i| {
+					let mut v = vec![BigInt::zero(); j];
+					v[i] = m.clone();
+					v
+				}))
+				.collect();
+			lll(&mut basis);
+			let h = Polynomial::from(basis[0].clone()).primitive_part();
+			if h.degree() >= 1 && divrem_mod(&h, &u_mod_p, p).1.is_zero() {
+				if let Some(q) = Polynomial::checked_exact_division(&g, &h) {
+					factor = Some((h, q));
+					break;
+				}
+			}
+		}
+		let (h, q) = factor.unwrap_or_else(|| (g.primitive_part(), polynomial![BigInt::one()]));
+		let h_mod_p = reduce(&h, p);
+		lifted.retain(|f| !divrem_mod(&h_mod_p, &reduce(f, p), p).1.is_zero());
+		g = q;
+		result.push(h);
+	}
+	if g.degree() >= 1 {
+		result.push(g.primitive_part());
+	}
+	result
+}
+
+/// Returns the monic irreducible factors of `g` modulo `P` when `P` does not
+/// divide the leading coefficient of `g` and `g` stays square-free modulo `P`.
+fn modular_factors<const P: u64>(g: &BigPolynomial) -> Option<Vec<BigPolynomial>> {
+	let p = BigInt::from(P);
+	let g_mod: Polynomial<Fp<P>> =
+		g.0.iter()
+			.map(|c| Fp::new(c.mod_floor(&p).to_u64().unwrap()))
+			.collect();
+	if g_mod.degree() != g.degree()
+		|| Polynomial::gcd_float_immutable(&g_mod, &g_mod.derivative()).degree() > 0
+	{
+		return None;
+	}
+	Some(
+		g_mod
+			.factor()
+			.into_iter()
+			.map(|(f, _)| f.0.iter().map(|c| BigInt::from(c.value())).collect())
+			.collect(),
+	)
+}
+
+type ModularFactorization = fn(&BigPolynomial) -> Option<Vec<BigPolynomial>>;
+
+macro_rules! prime_table {
+	($($p:literal),*) => {
+		[$(($p, modular_factors::<$p> as ModularFactorization)),*]
+	};
+}
+
+/// The primes among which the modulus of the factorization is chosen.
+const PRIMES: [(u64, ModularFactorization); 40] = prime_table!(
+	3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+	101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179
+);
+
+/// The number of suitable primes tried before choosing the one giving the
+/// fewest modular factors.
+const NB_PRIME_TRIALS: usize = 3;
+
+/// Factors a primitive square-free polynomial with a positive leading
+/// coefficient into irreducible factors, or returns `None` if none of the
+/// `PRIMES` keeps it square-free with the same degree.
+fn factor_square_free(g: &BigPolynomial, use_lll: bool) -> Option<Vec<BigPolynomial>> {
+	let n = g.degree();
+	if n <= 1 {
+		return Some(vec![g.clone()]);
+	}
+	let (p, factors) = PRIMES
+		.iter()
+		.filter_map(|(p, modular)| Some((*p, modular(g)?)))
+		.take(NB_PRIME_TRIALS)
+		.min_by_key(|(_, factors)| factors.len())?;
+	if factors.len() == 1 {
+		return Some(vec![g.clone()]);
+	}
+
+	let p_big = BigInt::from(p);
+	let mut k = 1;
+	let mut m = p_big.clone();
+	if use_lll {
+		// The first vector of a reduced basis of the polynomials of degree at most
+		// n - 1 divisible by a lifted factor of degree d is the irreducible factor
+		// of g that it divides, when it has a smaller degree, as soon as
+		// p^(kd) > 2^(n(n - 1)/2) C(2n - 2, n - 1)^(n/2) |g|^(2n - 1), see
+		// Proposition 2.7 of Lenstra, Lenstra and Lovász. This is checked squared,
+		// for the smallest d
+		let d = factors.iter().map(|f| f.degree()).min().unwrap() as u32;
+		let binomial = (1..n).fold(BigInt::one(), |c, i| c * (n - 1 + i) / i);
+		let norm_sq: BigInt = g.0.iter().map(|c| c * c).sum();
+		let bound = (binomial.pow(n as u32) * norm_sq.pow(2 * n as u32 - 1)) << (n * (n - 1));
+		while m.pow(2 * d) <= bound {
+			m *= &p_big;
+			k += 1;
+		}
+	} else {
+		// Mignotte's bound
+		let norm_2 = g.0.iter().map(|c| c * c).sum::<BigInt>().sqrt() + 1;
+		let bound = ((g[n].abs() * norm_2) << n) * 2;
+		while m <= bound {
+			m *= &p_big;
+			k += 1;
+		}
+	}
+
+	let lifted = hensel_lift(g, &factors, &p_big, k);
+	Some(if use_lll {
+		lll_recombination(g.clone(), lifted, &p_big, &m)
+	} else {
+		zassenhaus_recombination(g.clone(), lifted, &m)
+	})
+}
+
+impl<T> Polynomial<T>
+where
+	T: IntegerLike + Into<BigInt> + TryFrom<BigInt>,
+{
+	fn factor_with(&self, use_lll: bool) -> Option<(T, Vec<(Self, usize)>)> {
+		if self.is_zero() {
+			return None;
+		}
+		let mut content = self.content();
+		if self[self.degree()].is_negative() {
+			content = -content;
+		}
+		let f: BigPolynomial = self.0.iter().cloned().map(Into::into).collect();
+		let mut factors = Vec::new();
+		for (g, m) in f.square_free_decomposition() {
+			for h in factor_square_free(&g, use_lll)? {
+				factors.push((h, m));
+			}
+		}
+		factors
+			.sort_by(|(p1, m1), (p2, m2)| (p1.degree(), &p1.0, m1).cmp(&(p2.degree(), &p2.0, m2)));
+		let factors = factors
+			.into_iter()
+			.map(|(h, m)| {
+				let h =
+					h.0.into_iter()
+						.map(|c| T::try_from(c).ok())
+						.collect::<Option<Vec<_>>>()?;
+				Some((Self::from(h), m))
+			})
+			.collect::<Option<Vec<_>>>()?;
+		Some((content, factors))
+	}
+
+	/// Factors the polynomial into irreducible factors over ℤ.
+	///
+	/// Returns `(c, factors)` such that the polynomial equals `c` times the
+	/// product of the `g^m` for `(g, m)` in `factors`, where `c` is the signed
+	/// content of the polynomial and the `g` are primitive, irreducible and have
+	/// a positive leading coefficient. The factors are sorted by increasing
+	/// degree.
+	///
+	/// The polynomial is first made square-free with Yun's algorithm. Each
+	/// square-free part is factored modulo a small prime, the factorization is
+	/// lifted with Hensel's lemma above Mignotte's bound, and the true factors
+	/// are recombined from the lifted ones with Zassenhaus's algorithm. The
+	/// recombination is exponential in the worst case, see `factor_lll` for a
+	/// polynomial time alternative. The computations are done with `BigInt`
+	/// coefficients, whatever the coefficient type.
+	///
+	/// Returns `None` if the polynomial is zero, if one of its factors does not
+	/// fit in the coefficient type, or if no prime up to 179 keeps a
+	/// square-free part square-free modulo that prime.
+	///
+	/// # Example
+	/// ```rust
+	/// use num::BigInt;
+	/// use polyx::*;
+	/// // -2 (X - 1)^2 (X^2 + 1)
+	/// let p = polynomial![-2i64, 4, -4, 4, -2];
+	/// assert_eq!(
+	///   p.factor(),
+	///   Some((-2, vec![(polynomial![-1, 1], 2), (polynomial![1, 0, 1], 1)]))
+	/// );
+	///
+	/// // (X - 2^70)(X + 1)
+	/// let a = BigInt::from(2).pow(70);
+	/// let p = polynomial![-a.clone(), BigInt::from(1)] * polynomial![BigInt::from(1), BigInt::from(1)];
+	/// let (c, factors) = p.factor().unwrap();
+	/// assert_eq!(c, BigInt::from(1));
+	/// assert_eq!(factors[0].0, polynomial![-a, BigInt::from(1)]);
+	/// ```
+	pub fn factor(&self) -> Option<(T, Vec<(Self, usize)>)> {
+		self.factor_with(false)
+	}
+
+	/// Same function as `factor`, but the lifted factors are recombined with
+	/// lattice reduction (the algorithm of Lenstra, Lenstra and Lovász) instead
+	/// of trying their products. This avoids the exponential worst case of
+	/// Zassenhaus's algorithm, at the price of a larger lifting modulus, which
+	/// is chosen from the smallest degree of the modular factors.
+	pub fn factor_lll(&self) -> Option<(T, Vec<(Self, usize)>)> {
+		self.factor_with(true)
+	}
+}
@@ -1,7 +1,11 @@
 mod add;
+mod calculus;
 mod degree_shift;
 mod div_algos;
 mod gcd;
 mod inner_macros;
+mod integer_factor;
 mod multiply;
 mod multiply_algos;
+mod recurrence;
+mod square_free;
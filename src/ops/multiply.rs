@@ -3,10 +3,13 @@ use std::{
 	ops::{Mul, MulAssign, Sub},
 };
 
-use num_traits::{One, PrimInt, Zero};
+use num_traits::{One, PrimInt, Signed, Zero};
 
 use super::inner_macros::*;
-use crate::{traits::HasNorm, Polynomial};
+use crate::{
+	traits::{pow_by_squaring, CoefficientLiteral, FloatLike, HasNorm},
+	Polynomial,
+};
 
 impl<T> Mul<&Polynomial<T>> for &Polynomial<T>
 where
@@ -65,6 +68,122 @@ where
 	}
 }
 
+impl<T> Polynomial<T>
+where
+	T: CoefficientLiteral,
+{
+	/// Same as the product `self * other`, but returns `None` instead of
+	/// overflowing the coefficient type, as used by expressions. The
+	/// coefficients are computed by the schoolbook method with
+	/// `CoefficientLiteral::checked_product` and `checked_sum`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let p = polynomial![1i8, 1];
+	/// assert_eq!(p.checked_mul(&p), Some(polynomial![1, 2, 1]));
+	/// assert_eq!(polynomial![100i8, 1].checked_mul(&polynomial![1, 2]), None);
+	/// ```
+	pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+		if self.is_zero() || other.is_zero() {
+			return Some(Self::zero());
+		}
+		let mut coefficients = vec![T::zero(); self.0.len() + other.0.len() - 1];
+		for (i, a) in self.0.iter().enumerate() {
+			for (j, b) in other.0.iter().enumerate() {
+				coefficients[i + j] = coefficients[i + j].checked_sum(&a.checked_product(b)?)?;
+			}
+		}
+		Some(Self::from(coefficients))
+	}
+
+	/// Same as `powi`, but returns `None` instead of overflowing the
+	/// coefficient type, see `checked_mul`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let p = polynomial![1, 1];
+	/// assert_eq!(p.checked_powi(3), Some(polynomial![1, 3, 3, 1]));
+	/// assert_eq!(p.checked_powi(40), None);
+	/// ```
+	pub fn checked_powi(&self, n: u64) -> Option<Self> {
+		pow_by_squaring(self, n, Self::checked_mul)
+	}
+}
+
+impl<T> Polynomial<T>
+where
+	T: Clone + Debug + Signed + HasNorm,
+{
+	/// Computes the integer power of a polynomial modulo `modulus` by binary
+	/// exponentiation, reducing with `euclidean_division` after each product.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `modulus` is zero or if its leading
+	/// coefficient is not 1 or -1.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use polyx::*;
+	///
+	/// // X^2 = -2 modulo X^2 + 2, hence X^10 = (-2)^5
+	/// let x = polynomial![0, 1];
+	/// assert_eq!(x.powmod(10u64, &polynomial![2, 0, 1]), polynomial![-32]);
+	/// ```
+	pub fn powmod<U: PrimInt + Debug>(&self, exp: U, modulus: &Self) -> Self {
+		let mut n: u64 = exp
+			.to_u64()
+			.unwrap_or_else(|| panic!("Could not convert exponent {exp:?} to u64"));
+		let remainder = |p: &Self| Self::euclidean_division_immutable(p, modulus).1;
+		let mut base = remainder(self);
+		let mut result = remainder(&Self::one());
+		while n > 0 {
+			if n & 1 == 1 {
+				result = remainder(&(&result * &base));
+			}
+			n >>= 1;
+			if n > 0 {
+				base = remainder(&(&base * &base));
+			}
+		}
+		result
+	}
+}
+
+impl<T> Polynomial<T>
+where
+	T: FloatLike,
+{
+	/// Same function as `powmod`, but for floating point coefficients and
+	/// coefficients in a field, reducing with `euclidean_division_float`. The
+	/// leading coefficient of `modulus` only has to be invertible.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `modulus` is zero.
+	pub fn powmod_float<U: PrimInt + Debug>(&self, exp: U, modulus: &Self) -> Self {
+		let mut n: u64 = exp
+			.to_u64()
+			.unwrap_or_else(|| panic!("Could not convert exponent {exp:?} to u64"));
+		let remainder = |p: &Self| Self::euclidean_division_immutable_float(p, modulus).1;
+		let mut base = remainder(self);
+		let mut result = remainder(&Self::one());
+		while n > 0 {
+			if n & 1 == 1 {
+				result = remainder(&(&result * &base));
+			}
+			n >>= 1;
+			if n > 0 {
+				base = remainder(&(&base * &base));
+			}
+		}
+		result
+	}
+}
+
 impl<T> Polynomial<T>
 where
 	T: Mul<T, Output = T> + Sub<T, Output = T> + Clone + Zero + Debug + HasNorm,
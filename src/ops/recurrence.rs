@@ -0,0 +1,191 @@
+use std::fmt::Debug;
+
+use num_traits::{Num, One, PrimInt, Signed};
+
+use crate::{
+	traits::{FloatLike, HasNorm},
+	Polynomial,
+};
+
+/// Combines the initial terms with the coefficients of `X^n` modulo the
+/// characteristic polynomial.
+fn combine<T>(remainder: &Polynomial<T>, initial: &[T]) -> T
+where
+	T: Num + Clone,
+{
+	remainder
+		.0
+		.iter()
+		.zip(initial)
+		.fold(T::zero(), |acc, (r, s)| acc + r.clone() * s.clone())
+}
+
+/// Checks the arguments of `recurrence_term` and returns `n` as a `u64`,
+/// along with whether it indexes one of the initial terms.
+fn check_recurrence<T, U>(charpoly: &Polynomial<T>, initial: &[T], n: U) -> (u64, bool)
+where
+	U: PrimInt + Debug,
+{
+	let n = n
+		.to_u64()
+		.unwrap_or_else(|| panic!("Could not convert index {n:?} to u64"));
+	if charpoly.0.len() < 2 {
+		panic!("The characteristic polynomial of a recurrence must have a positive degree");
+	}
+	if initial.len() < charpoly.0.len() - 1 {
+		panic!(
+			"A recurrence of order {} needs as many initial terms, got {}",
+			charpoly.0.len() - 1,
+			initial.len()
+		);
+	}
+	(n, n < initial.len() as u64)
+}
+
+impl<T> Polynomial<T>
+where
+	T: Clone + Debug + Signed + HasNorm,
+{
+	/// Computes the `n`-th term of the sequence defined by the linear recurrence
+	/// whose characteristic polynomial is `self` and by its first terms, with
+	/// Fiduccia's algorithm.
+	///
+	/// The polynomial `X^d - a_1 X^(d-1) - ... - a_d` defines the recurrence
+	/// `s_n = a_1 s_(n-1) + ... + a_d s_(n-d)`; it is the reverse of the
+	/// connection polynomial returned by `berlekamp_massey`. Writing `X^n`
+	/// modulo `self` as `r_0 + r_1 X + ... + r_(d-1) X^(d-1)` with `powmod`
+	/// gives `s_n = r_0 s_0 + ... + r_(d-1) s_(d-1)`, in `O(log n)`
+	/// multiplications of polynomials of degree `d`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `self` is constant, if its leading
+	/// coefficient is not 1 or -1, or if fewer than `d` initial terms are given.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// // Fibonacci: s_n = s_(n-1) + s_(n-2)
+	/// let charpoly = polynomial![-1i64, -1, 1];
+	/// assert_eq!(charpoly.recurrence_term(&[0, 1], 90u64), 2880067194370816120);
+	/// ```
+	pub fn recurrence_term<U: PrimInt + Debug>(&self, initial: &[T], n: U) -> T {
+		let (n, is_initial) = check_recurrence(self, initial, n);
+		if is_initial {
+			return initial[n as usize].clone();
+		}
+		combine(
+			&crate::polynomial![T::zero(), T::one()].powmod(n, self),
+			initial,
+		)
+	}
+}
+
+impl<T> Polynomial<T>
+where
+	T: FloatLike,
+{
+	/// Same function as `recurrence_term`, but for floating point coefficients
+	/// and coefficients in a field, with `powmod_float`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `self` is constant or if fewer than `d`
+	/// initial terms are given.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{fields::Fp, *};
+	/// type F = Fp<1_000_000_007>;
+	/// // Fibonacci numbers modulo 10^9 + 7
+	/// let charpoly = polynomial![F::from(-1), F::from(-1), F::from(1)];
+	/// let term = charpoly.recurrence_term_float(&[F::from(0), F::from(1)], 1_000_000_000_000_000_000u64);
+	/// assert_eq!(term, F::from(209_783_453));
+	/// ```
+	pub fn recurrence_term_float<U: PrimInt + Debug>(&self, initial: &[T], n: U) -> T {
+		let (n, is_initial) = check_recurrence(self, initial, n);
+		if is_initial {
+			return initial[n as usize].clone();
+		}
+		combine(
+			&crate::polynomial![T::zero(), T::one()].powmod_float(n, self),
+			initial,
+		)
+	}
+
+	/// Finds the shortest linear recurrence satisfied by a sequence with
+	/// Berlekamp–Massey's algorithm.
+	///
+	/// Returns the connection polynomial `C = 1 + c_1 X + ... + c_L X^L` such
+	/// that `s_n + c_1 s_(n-1) + ... + c_L s_(n-L) = 0` for every `L <= n <
+	/// sequence.len()`, with `L` minimal. The recurrence is uniquely determined
+	/// when the sequence has at least `2L` terms. Trailing coefficients `c_L`
+	/// equal to zero are trimmed, so that the degree of `C` may be lower than
+	/// `L` when the sequence starts with terms that do not follow the
+	/// recurrence.
+	///
+	/// With floating point coefficients, the discrepancies whose norm is below
+	/// `TOL` are considered to be zero. Over prime fields and rationals the
+	/// result is exact.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{fields::Fp, *};
+	/// // Fibonacci: s_n - s_(n-1) - s_(n-2) = 0
+	/// let fibonacci = [1., 1., 2., 3., 5., 8., 13., 21.];
+	/// assert_eq!(Polynomial::berlekamp_massey(&fibonacci), polynomial![1., -1., -1.]);
+	///
+	/// type F = Fp<7>;
+	/// let powers: Vec<F> = (0..6).map(|i| F::from(3).pow(i)).collect();
+	/// assert_eq!(Polynomial::berlekamp_massey(&powers), polynomial![F::from(1), F::from(-3)]);
+	/// ```
+	pub fn berlekamp_massey(sequence: &[T]) -> Self {
+		Self::berlekamp_massey_from(sequence, Self::one(), 0)
+	}
+
+	/// Berlekamp–Massey's algorithm started from a connection polynomial of
+	/// length `initial_length` that is already known, e.g. the erasure locator
+	/// of a Reed–Solomon decoder as described by Blahut. The first
+	/// `initial_length` terms of the sequence are skipped.
+	pub(crate) fn berlekamp_massey_from(
+		sequence: &[T],
+		initial: Self,
+		initial_length: usize,
+	) -> Self {
+		let mut connection = initial.clone();
+		let mut previous = initial;
+		let mut length = initial_length;
+		for r in initial_length..sequence.len() {
+			let discrepancy = connection
+				.0
+				.iter()
+				.zip(sequence[..=r].iter().rev())
+				.fold(T::zero(), |acc, (c, s)| acc + c.clone() * s.clone());
+			previous <<= 1;
+			if discrepancy.is_negligible() {
+				continue;
+			}
+			let updated = &connection
+				- &Polynomial::from(
+					previous
+						.0
+						.iter()
+						.map(|c| c.clone() * discrepancy.clone())
+						.collect::<Vec<_>>(),
+				);
+			if 2 * length <= r + initial_length {
+				let inverse = discrepancy.inv();
+				previous = Polynomial::from(
+					connection
+						.0
+						.iter()
+						.map(|c| c.clone() * inverse.clone())
+						.collect::<Vec<_>>(),
+				);
+				length = r + initial_length + 1 - length;
+			}
+			connection = updated;
+		}
+		connection
+	}
+}
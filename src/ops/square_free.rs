@@ -0,0 +1,128 @@
+use num_traits::Zero;
+
+use crate::{
+	traits::{FloatLike, IntegerLike},
+	Polynomial,
+};
+
+impl<T> Polynomial<T>
+where
+	T: IntegerLike,
+{
+	/// Computes the square-free decomposition of the polynomial in ℤ\[X\] with
+	/// Yun's algorithm.
+	///
+	/// Returns the pairs `(a_i, i)` such that the primitive part of the
+	/// polynomial is the product of the `a_i^i`, where the `a_i` are primitive,
+	/// square-free, pairwise coprime and have a positive leading coefficient.
+	/// The factors equal to 1 are omitted. All the divisions are exact.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// // (X - 1)^2 (X + 2)^3
+	/// let p = polynomial![-1, 1].powi(2) * polynomial![2, 1].powi(3);
+	/// assert_eq!(
+	///   p.square_free_decomposition(),
+	///   vec![(polynomial![-1, 1], 2), (polynomial![2, 1], 3)]
+	/// );
+	/// ```
+	pub fn square_free_decomposition(&self) -> Vec<(Self, usize)> {
+		if self.is_zero() {
+			panic!("The zero polynomial has no square-free decomposition");
+		}
+		let f = self.primitive_part();
+		let df = f.derivative();
+		let a0 = Self::gcd_prs(&f, &df);
+		let mut b = Self::exact_division(&f, &a0);
+		let mut d = &Self::exact_division(&df, &a0) - &b.derivative();
+		let mut result = Vec::new();
+		let mut i = 1;
+		while b.degree() > 0 {
+			let a = Self::gcd_prs(&b, &d);
+			b = Self::exact_division(&b, &a);
+			let c = Self::exact_division(&d, &a);
+			d = &c - &b.derivative();
+			if a.degree() > 0 {
+				result.push((a, i));
+			}
+			i += 1;
+		}
+		result
+	}
+
+	/// Returns the square-free part of the polynomial in ℤ\[X\], i.e. the
+	/// product of its distinct primitive irreducible factors.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	pub fn square_free_part(&self) -> Self {
+		if self.is_zero() {
+			panic!("The zero polynomial has no square-free part");
+		}
+		let f = self.primitive_part();
+		Self::exact_division(&f, &Self::gcd_prs(&f, &f.derivative()))
+	}
+}
+
+impl<T> Polynomial<T>
+where
+	T: FloatLike,
+{
+	/// Same function as `square_free_decomposition`, but for floating point
+	/// coefficients. The gcds are computed with `gcd_float`, hence coefficients
+	/// below `TOL` are considered to be zero.
+	///
+	/// The returned factors are monic, so their product equals the polynomial
+	/// up to its leading coefficient.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	pub fn square_free_decomposition_float(&self) -> Vec<(Self, usize)> {
+		if self.is_zero() {
+			panic!("The zero polynomial has no square-free decomposition");
+		}
+		let df = self.derivative();
+		let a0 = Self::gcd_float_immutable(self, &df);
+		let (mut b, _) = Self::euclidean_division_immutable_float(self, &a0);
+		let (c, _) = Self::euclidean_division_immutable_float(&df, &a0);
+		let mut d = &c - &b.derivative();
+		let mut result = Vec::new();
+		let mut i = 1;
+		// Yun's algorithm needs at most deg(self) steps, the bound only guards against
+		// numerical noise
+		while b.degree() > 0 && i <= self.degree() {
+			let a = Self::gcd_float_immutable(&b, &d);
+			(b, _) = Self::euclidean_division_immutable_float(&b, &a);
+			let (c, _) = Self::euclidean_division_immutable_float(&d, &a);
+			d = &c - &b.derivative();
+			if a.degree() > 0 {
+				result.push((a, i));
+			}
+			i += 1;
+		}
+		result
+	}
+
+	/// Same function as `square_free_part`, but for floating point coefficients.
+	/// The result is monic.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	pub fn square_free_part_float(&self) -> Self {
+		if self.is_zero() {
+			panic!("The zero polynomial has no square-free part");
+		}
+		let gcd = Self::gcd_float_immutable(self, &self.derivative());
+		let (q, _) = Self::euclidean_division_immutable_float(self, &gcd);
+		let lc_inv = q[q.degree()].clone().inv();
+		q.into_iter().map(|x| x * lc_inv.clone()).collect()
+	}
+}
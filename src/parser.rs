@@ -1,375 +1,608 @@
-use std::fmt::{self, Debug};
-
-use num_traits::{ToPrimitive, Zero};
+use std::{io::BufRead, iter::Peekable, str::CharIndices, str::FromStr};
 
 use crate::{
 	errors::PolynomialError::{self, *},
-	polynomial,
-	traits::Primitive,
+	expr::{
+		parse_expr_with, BinaryOp, Environment, Expr, ExprKind, Literal, ParseError, ParserOptions,
+		Span,
+	},
+	traits::CoefficientLiteral,
 	Polynomial,
 };
 
-#[derive(PartialEq, Debug, Clone, Copy)]
-pub(crate) enum Ops {
-	Add,
-	Min,
-	Mul,
-	Div,
-	Pow,
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+	/// A numeric literal with its imaginary units. A lone `i` is the literal
+	/// `1i`.
+	Literal(Literal),
+	Variable(String),
+	/// Any other word, naming a parameter or a function.
+	Name(String),
+	Op(BinaryOp),
 	Open,
+	Close,
+	/// The separator of the arguments of a function.
+	Comma,
+}
+
+/// The tokens of a polynomial expression. Unicode superscripts are read as
+/// `^` followed by a literal, both spanning the superscript digits.
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+	kind: TokenKind,
+	span: Span,
 }
 
-impl fmt::Display for Ops {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		use Ops::*;
-		match self {
-			Add => write!(f, "'+'"),
-			Min => write!(f, "'-'"),
-			Mul => write!(f, "'*'"),
-			Div => write!(f, "'/'"),
-			Pow => write!(f, "'^'"),
-			Open => write!(f, "'('"),
+fn superscript_digit(c: char) -> Option<char> {
+	match c {
+		'⁰' => Some('0'),
+		'¹' => Some('1'),
+		'²' => Some('2'),
+		'³' => Some('3'),
+		'⁴' => Some('4'),
+		'⁵' => Some('5'),
+		'⁶' => Some('6'),
+		'⁷' => Some('7'),
+		'⁸' => Some('8'),
+		'⁹' => Some('9'),
+		_ => None,
+	}
+}
+
+/// Reads digits in the given radix from `bytes[*end..]`, which may be grouped
+/// with single underscores between two digits, and returns them without the
+/// underscores, or `None` if an underscore is misplaced.
+fn read_digits(bytes: &[u8], end: &mut usize, radix: u32) -> Option<String> {
+	let is_digit = |i: usize| bytes.get(i).is_some_and(|&b| (b as char).is_digit(radix));
+	let mut digits = String::new();
+	while let Some(&b) = bytes.get(*end) {
+		if is_digit(*end) {
+			digits.push(b as char);
+		} else if b == b'_' {
+			if digits.is_empty() || !is_digit(*end + 1) {
+				return None;
+			}
+		} else {
+			break;
 		}
+		*end += 1;
 	}
+	Some(digits)
 }
 
-impl Ops {
-	pub(crate) fn prio(&self) -> u32 {
-		use Ops::*;
-		match self {
-			Mul => 2,
-			Div => 2,
-			Pow => 3,
-			Open => 0,
-			_ => 1,
+/// Reads the numeric literal starting at `start`, without its imaginary
+/// units, and returns it with the offset of its end.
+///
+/// Decimal literals have an optional fractional part after a `.` or a `,`
+/// and an optional exponent, as in `6.02e23` or `1.5E-3`, where the `,` is
+/// only allowed with `decimal_comma`. Integers may also
+/// be written in hexadecimal or binary with the prefixes `0x` and `0b`, and
+/// digits may be grouped with underscores, as in `1_000_000`.
+fn read_number(s: &str, start: usize, decimal_comma: bool) -> Result<(Literal, usize), ParseError> {
+	let bytes = s.as_bytes();
+	let is_point = |i: usize| match bytes.get(i) {
+		Some(b'.') => true,
+		Some(b',') => decimal_comma,
+		_ => false,
+	};
+	let is_digit =
+		|i: usize, radix: u32| bytes.get(i).is_some_and(|&b| (b as char).is_digit(radix));
+	// The error spans the whole malformed word, e.g. `1.2.3` or `0b102`
+	let malformed = |mut end: usize| {
+		while bytes
+			.get(end)
+			.is_some_and(|&b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b',' | b'_'))
+		{
+			end += 1;
 		}
+		ParseError::new(
+			MalformedLiteral(s[start..end].to_string()),
+			Span { start, end },
+		)
+	};
+	let mut end = start;
+	let mut literal = Literal::integer(String::new(), 0);
+	let radix = match bytes.get(start..start + 2) {
+		Some(b"0x" | b"0X") => 16,
+		Some(b"0b" | b"0B") => 2,
+		_ => 10,
+	};
+	// Without a digit, as in `0x`, the prefix is a product with the variable
+	if radix != 10 && is_digit(start + 2, radix) {
+		end += 2;
+		literal.radix = radix;
+		literal.integer = read_digits(bytes, &mut end, radix).ok_or_else(|| malformed(end))?;
+		if bytes.get(end).is_some_and(u8::is_ascii_digit) || is_point(end) {
+			return Err(malformed(end));
+		}
+		return Ok((literal, end));
+	}
+
+	literal.integer = read_digits(bytes, &mut end, 10).ok_or_else(|| malformed(end))?;
+	if is_point(end) {
+		end += 1;
+		literal.decimals = read_digits(bytes, &mut end, 10).ok_or_else(|| malformed(end))?;
+		if literal.integer.is_empty() && literal.decimals.is_empty() {
+			return Err(malformed(end));
+		}
+	}
+	// An `e` that is not followed by digits starts a word, as in `2exp`
+	if matches!(bytes.get(end), Some(b'e' | b'E')) {
+		let signed = matches!(bytes.get(end + 1), Some(b'+' | b'-'));
+		let digits = end + 1 + signed as usize;
+		if is_digit(digits, 10) {
+			let mut exponent_end = digits;
+			let exponent =
+				read_digits(bytes, &mut exponent_end, 10).ok_or_else(|| malformed(exponent_end))?;
+			let exponent = format!(
+				"{}{exponent}",
+				if bytes[end + 1] == b'-' { "-" } else { "" }
+			);
+			literal.exponent = exponent.parse().map_err(|_| {
+				ParseError::new(
+					LiteralOverflow(s[start..exponent_end].to_string()),
+					Span {
+						start,
+						end: exponent_end,
+					},
+				)
+			})?;
+			end = exponent_end;
+		} else if signed {
+			return Err(malformed(digits));
+		}
+	}
+	if is_point(end) {
+		return Err(malformed(end));
 	}
+	Ok((literal, end))
 }
 
-struct Parser<T> {
-	pols_vec: Vec<Polynomial<T>>,
-	ops_vec: Vec<Ops>,
-	reads_num: bool,
-	reads_dec: bool,
-	num: u64,
-	nb_decs: u32,
-	unary_min: bool,
-	is_min: bool,
-	is_factor: bool,
+/// Counts the imaginary units that directly follow a literal.
+fn read_imaginary(chars: &mut Peekable<CharIndices>) -> u32 {
+	let mut imaginary = 0;
+	while chars.next_if(|&(_, c)| c == 'i' || c == 'j').is_some() {
+		imaginary += 1;
+	}
+	imaginary
 }
 
-impl<T> Parser<T>
-where
-	T: Primitive,
-{
-	fn execute_bin_operator(&mut self) -> Result<(), PolynomialError> {
-		#[allow(non_snake_case)]
-		let X = polynomial![T::zero(), T::one()];
-		// println!("Got {:?} and {:?}", self.pols_vec, op);
-		let op: Ops = self.ops_vec.pop().ok_or(NoBinaryOperator)?;
-		let p1: Polynomial<T> = self.pols_vec.pop().ok_or(BinaryOperatorZeroOperand(op))?;
-		let p2: Polynomial<T> = self
-			.pols_vec
-			.pop()
-			.ok_or(BinaryOperatorOneOperand(op, p1.to_string()))?;
-
-		if op == Ops::Mul
-			&& !self.ops_vec.is_empty()
-			&& self.ops_vec[self.ops_vec.len() - 1] == Ops::Pow
-			&& !self.pols_vec.is_empty()
-			&& self.pols_vec[self.pols_vec.len() - 1].degree() == 0
-			&& p1.degree() == 0
-			&& p2 == X
-		{
-			if self.pols_vec[self.pols_vec.len() - 1].is_zero() {
-				return Err(ImpossiblePower(p2.to_string(), p1[0].to_string()));
+/// Splits a word into the indeterminate and imaginary units, as in `iX`, or
+/// returns `None` if it is a name.
+fn split_word(word: &str, start: usize, options: &ParserOptions) -> Option<Vec<Token>> {
+	let mut tokens = Vec::new();
+	let mut position = 0;
+	while position < word.len() {
+		let rest = &word[position..];
+		let (kind, length) = if let Some(length) = options.variable_prefix(rest) {
+			(TokenKind::Variable(rest[..length].to_string()), length)
+		} else {
+			let length = rest.len() - rest.trim_start_matches(['i', 'j']).len();
+			if length == 0 {
+				return None;
 			}
-			// This is to optimize a bit the parsing of an expression like "cX^pow"
-			let c: T = self.pols_vec.pop().unwrap()[0].clone();
-			let pow: f64 = p1[0].to_f64().unwrap();
-			if pow == pow.round() && pow >= 0. {
-				self.ops_vec.pop();
-				self.pols_vec.push(polynomial![c] << pow as usize);
-				Ok(())
-			} else {
-				Err(ImpossiblePower(p2.to_string(), c.to_string()))
+			let kind = TokenKind::Literal(Literal::integer("1".to_string(), length as u32));
+			(kind, length)
+		};
+		tokens.push(Token {
+			kind,
+			span: Span {
+				start: start + position,
+				end: start + position + length,
+			},
+		});
+		position += length;
+	}
+	Some(tokens)
+}
+
+fn tokenize(s: &str, options: &ParserOptions) -> Result<Vec<Token>, ParseError> {
+	let mut tokens = Vec::new();
+	let mut chars = s.char_indices().peekable();
+	// Whether each open parenthesis starts the arguments of a function, inside
+	// which commas separate the arguments instead of decimals
+	let mut calls = Vec::new();
+	while let Some((start, c)) = chars.next() {
+		let in_call = calls.last() == Some(&true);
+		let kind = match c {
+			c if c.is_whitespace() => continue,
+			',' if in_call => TokenKind::Comma,
+			'0'..='9' | '.' | ',' => {
+				let (mut literal, end) = read_number(s, start, !in_call)?;
+				while chars.next_if(|&(i, _)| i < end).is_some() {}
+				literal.imaginary = read_imaginary(&mut chars);
+				TokenKind::Literal(literal)
 			}
-		} else {
-			match op {
-				Ops::Add => self.pols_vec.push(p2 + p1),
-				Ops::Min => self.pols_vec.push(p2 - p1),
-				Ops::Mul => self.pols_vec.push(p2 * p1),
-				Ops::Div => {
-					if p1.degree() == 0 {
-						let c: T = T::one() / p1[0].clone();
-						self.pols_vec.push(polynomial![c] * p2)
-					} else {
-						return Err(ImpossibleDivision(p2.to_string(), p1.to_string()));
+			c if c.is_alphabetic() => {
+				// Digits are allowed in a subscript, as in `a_1`
+				let mut subscript = false;
+				while let Some((_, c)) = chars.next_if(|&(_, c)| {
+					c.is_alphabetic() || c == '_' || (subscript && c.is_ascii_digit())
+				}) {
+					subscript |= c == '_';
+				}
+				let end = chars.peek().map_or(s.len(), |&(i, _)| i);
+				let word = &s[start..end];
+				match split_word(word, start, options) {
+					Some(words) => {
+						tokens.extend(words);
+						continue;
 					}
+					None if word == "mod" => TokenKind::Op(BinaryOp::Rem),
+					None => TokenKind::Name(word.to_string()),
+				}
+			}
+			'+' => TokenKind::Op(BinaryOp::Add),
+			'-' => TokenKind::Op(BinaryOp::Sub),
+			'*' => TokenKind::Op(BinaryOp::Mul),
+			'/' => TokenKind::Op(BinaryOp::Div),
+			'%' => TokenKind::Op(BinaryOp::Rem),
+			'^' => TokenKind::Op(BinaryOp::Pow),
+			'(' => {
+				calls.push(matches!(
+					tokens.last(),
+					Some(Token { kind: TokenKind::Name(_), span }) if span.end == start
+				));
+				TokenKind::Open
+			}
+			')' => {
+				calls.pop();
+				TokenKind::Close
+			}
+			c => {
+				let span = Span {
+					start,
+					end: start + c.len_utf8(),
+				};
+				let digit =
+					superscript_digit(c).ok_or(ParseError::new(UnsupportedCharacter(c), span))?;
+				let mut integer = digit.to_string();
+				while let Some(digit) = chars.next_if(|&(_, c)| superscript_digit(c).is_some()) {
+					integer.extend(superscript_digit(digit.1));
 				}
-				Ops::Pow => {
-					if p1.degree() == 0 {
-						let c: f64 = p1[0].to_f64().unwrap();
-						if p2.degree() == 0 {
-							let c2: f64 = p2[0].to_f64().unwrap();
-							self.pols_vec
-								.push(polynomial![T::from_f64(c2.powf(c)).unwrap()])
-						} else if c == c.round() {
-							if c.is_sign_negative() {
-								return Err(ImpossiblePower(p2.to_string(), p1[0].to_string()));
-							}
-							let r: Polynomial<T> = p2.powi(c.to_usize().unwrap());
-							self.pols_vec.push(r)
-						} else {
-							return Err(ImpossiblePower(p2.to_string(), p1[0].to_string()));
-						}
-					} else {
-						return Err(ImpossiblePower2Polynomials(p2.to_string(), p1.to_string()));
+				let span = Span {
+					start,
+					end: chars.peek().map_or(s.len(), |&(i, _)| i),
+				};
+				tokens.push(Token {
+					kind: TokenKind::Op(BinaryOp::Pow),
+					span,
+				});
+				TokenKind::Literal(Literal::integer(integer, 0))
+			}
+		};
+		let end = chars.peek().map_or(s.len(), |&(i, _)| i);
+		tokens.push(Token {
+			kind,
+			span: Span { start, end },
+		});
+	}
+	Ok(tokens)
+}
+
+/// What precedes an operand, to report the right error when it is missing.
+#[derive(Clone, Copy)]
+enum Before {
+	Start,
+	Open,
+	Operator(BinaryOp),
+	Minus,
+}
+
+/// The deepest nesting of parentheses, calls, minus signs and exponents in an
+/// expression.
+pub(crate) const MAX_NESTING: usize = 128;
+
+/// A recursive descent parser for the grammar
+///
+/// ```text
+/// sum     := product (('+' | '-') product)*
+/// product := unary (('*' | '/' | '%' | 'mod') unary | power)*
+/// unary   := '-' unary | power
+/// power   := atom ('^' unary)?
+/// atom    := literal | variable | name | name '(' (sum (',' sum)*)? ')' | '(' sum ')'
+/// ```
+///
+/// where a power directly following a product is an implicit multiplication,
+/// as in `2X(X + 1)`. A name directly followed by a parenthesis, without
+/// whitespace, is a call with its arguments. `^` is right associative and binds tighter than the
+/// unary minus, so that `-X^2` is `-(X^2)`.
+///
+/// Parentheses, calls, minus signs and exponents nest at most `MAX_NESTING`
+/// levels, beyond which a `NestingTooDeep` error is returned rather than
+/// overflowing the stack.
+pub(crate) struct Parser {
+	tokens: Vec<Token>,
+	position: usize,
+	/// The number of nested `unary` rules being parsed.
+	depth: usize,
+	/// The length of the source, where the errors at the end are located.
+	length: usize,
+	implicit_multiplication: bool,
+}
+
+impl Parser {
+	pub(crate) fn new(s: &str, options: &ParserOptions) -> Result<Self, ParseError> {
+		Ok(Parser {
+			tokens: tokenize(s, options)?,
+			position: 0,
+			depth: 0,
+			length: s.len(),
+			implicit_multiplication: options.allows_implicit_multiplication(),
+		})
+	}
+
+	fn peek(&self) -> Option<&TokenKind> {
+		self.tokens.get(self.position).map(|token| &token.kind)
+	}
+
+	fn next(&mut self) -> Option<Token> {
+		let token = self.tokens.get(self.position).cloned();
+		self.position += 1;
+		token
+	}
+
+	/// Returns the span of the next token, or an empty span at the end.
+	fn next_span(&self) -> Span {
+		self.tokens.get(self.position).map_or(
+			Span {
+				start: self.length,
+				end: self.length,
+			},
+			|token| token.span,
+		)
+	}
+
+	pub(crate) fn parse(mut self) -> Result<Expr, ParseError> {
+		let expr = self.parse_sum(Before::Start)?;
+		match self.peek() {
+			None => Ok(expr),
+			Some(_) => Err(ParseError::new(ImpossibleClose, self.next_span())),
+		}
+	}
+
+	fn binary(op: BinaryOp, lhs: Expr, rhs: Expr) -> Expr {
+		Expr {
+			span: lhs.span.to(rhs.span),
+			kind: ExprKind::Binary(op, Box::new(lhs), Box::new(rhs)),
+		}
+	}
+
+	fn parse_sum(&mut self, before: Before) -> Result<Expr, ParseError> {
+		let mut lhs = self.parse_product(before)?;
+		while let Some(&TokenKind::Op(op @ (BinaryOp::Add | BinaryOp::Sub))) = self.peek() {
+			self.next();
+			let rhs = self.parse_product(Before::Operator(op))?;
+			lhs = Self::binary(op, lhs, rhs);
+		}
+		Ok(lhs)
+	}
+
+	fn parse_product(&mut self, before: Before) -> Result<Expr, ParseError> {
+		let mut lhs = self.parse_unary(before)?;
+		loop {
+			let (op, rhs) = match self.peek() {
+				Some(&TokenKind::Op(op @ (BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem))) => {
+					self.next();
+					(op, self.parse_unary(Before::Operator(op))?)
+				}
+				Some(
+					TokenKind::Literal(_)
+					| TokenKind::Variable(_)
+					| TokenKind::Name(_)
+					| TokenKind::Open,
+				) => {
+					if !self.implicit_multiplication {
+						return Err(ParseError::new(MissingOperator, self.next_span()));
 					}
+					(BinaryOp::Mul, self.parse_power(Before::Start)?)
 				}
-				Ops::Open => return Err(ImpossibleOpen),
+				_ => return Ok(lhs),
 			};
-			Ok(())
+			lhs = Self::binary(op, lhs, rhs);
 		}
 	}
 
-	fn push_num(&mut self) -> Result<(), PolynomialError> {
-		if self.reads_num {
-			if self.is_factor {
-				// i.e. if there was a factor before the number
-				self.push_bin_operator(Ops::Mul)?;
-			}
-			if self.num == 0 {
-				self.pols_vec.push(Polynomial::zero());
-			} else if self.is_min {
-				self.pols_vec.push(polynomial![T::from_f64(
-					-(self.num as f64) / 10f64.powi(self.nb_decs as i32)
-				)
-				.unwrap()]);
-			} else {
-				self.pols_vec.push(polynomial![T::from_f64(
-					self.num as f64 / 10f64.powi(self.nb_decs as i32)
-				)
-				.unwrap()]);
-			}
-			self.num = 0;
-			self.nb_decs = 0;
-			self.reads_num = false;
-			self.reads_dec = false;
-			self.is_min = false;
-			self.is_factor = true; // There can be a factor after the number
+	fn parse_unary(&mut self, before: Before) -> Result<Expr, ParseError> {
+		// Every nested rule goes through this one
+		if self.depth == MAX_NESTING {
+			return Err(ParseError::new(NestingTooDeep, self.next_span()));
 		}
-		Ok(())
+		self.depth += 1;
+		let e = if let Some(TokenKind::Op(BinaryOp::Sub)) = self.peek() {
+			let minus = self.next_span();
+			self.next();
+			let e = self.parse_unary(Before::Minus)?;
+			Expr {
+				span: minus.to(e.span),
+				kind: ExprKind::Neg(Box::new(e)),
+			}
+		} else {
+			self.parse_power(before)?
+		};
+		self.depth -= 1;
+		Ok(e)
 	}
 
-	fn push_bin_operator(&mut self, op: Ops) -> Result<(), PolynomialError> {
-		if self.is_min {
-			Err(UnaryMinusFailed(op))
+	fn parse_power(&mut self, before: Before) -> Result<Expr, ParseError> {
+		let base = self.parse_atom(before)?;
+		if let Some(TokenKind::Op(BinaryOp::Pow)) = self.peek() {
+			self.next();
+			let exponent = self.parse_unary(Before::Operator(BinaryOp::Pow))?;
+			Ok(Self::binary(BinaryOp::Pow, base, exponent))
 		} else {
-			let p = op.prio();
-			while !self.ops_vec.is_empty() && self.ops_vec[self.ops_vec.len() - 1].prio() >= p {
-				self.execute_bin_operator()?;
-			}
-			self.ops_vec.push(op);
-			Ok(())
+			Ok(base)
 		}
 	}
 
-	fn read_digit(&mut self, n: u8) {
-		if self.reads_dec {
-			self.nb_decs += 1;
+	/// Parses the arguments of a call after its opening parenthesis, and
+	/// returns them with the span of the closing parenthesis.
+	fn parse_arguments(&mut self, open: Span) -> Result<(Vec<Expr>, Span), ParseError> {
+		let mut arguments = Vec::new();
+		if self.peek() != Some(&TokenKind::Close) {
+			loop {
+				arguments.push(self.parse_sum(Before::Open)?);
+				if self.peek() != Some(&TokenKind::Comma) {
+					break;
+				}
+				self.next();
+			}
+		}
+		let close = self.next_span();
+		match self.next().map(|token| token.kind) {
+			Some(TokenKind::Close) => Ok((arguments, close)),
+			_ => Err(ParseError::new(ImpossibleOpen, open)),
 		}
-		self.num = (n as u64) + 10 * (self.num);
-		self.reads_num = true;
-		self.unary_min = false;
 	}
 
-	fn read_char(&mut self, c: char) -> Result<(), PolynomialError> {
-		#[allow(non_snake_case)]
-		let X = polynomial![T::zero(), T::one()];
-		match c {
-			' ' => self.push_num()?,
-			'+' => {
-				self.push_num()?;
-				self.push_bin_operator(Ops::Add)?;
-				self.is_factor = false;
-				self.unary_min = true;
-			}
-			'-' => {
-				if self.unary_min {
-					self.unary_min = false;
-					self.is_min = true;
-					self.is_factor = false;
+	fn parse_atom(&mut self, before: Before) -> Result<Expr, ParseError> {
+		let span = self.next_span();
+		let token = self.next().map(|token| token.kind);
+		let kind = match token {
+			Some(TokenKind::Literal(literal)) => ExprKind::Literal(literal),
+			Some(TokenKind::Variable(name)) => ExprKind::Variable(name),
+			Some(TokenKind::Name(name)) => {
+				let open = self.next_span();
+				if self.peek() != Some(&TokenKind::Open) || open.start != span.end {
+					ExprKind::Name(name)
 				} else {
-					self.push_num()?;
-					self.push_bin_operator(Ops::Min)?;
-					self.is_factor = false;
-					self.unary_min = true;
+					self.next();
+					let (arguments, close) = self.parse_arguments(open)?;
+					return Ok(Expr {
+						kind: ExprKind::Call(name, arguments),
+						span: span.to(close),
+					});
 				}
 			}
-			'*' => {
-				self.push_num()?;
-				self.push_bin_operator(Ops::Mul)?;
-				self.is_factor = false;
-				self.unary_min = true;
+			Some(TokenKind::Open) => {
+				let e = self.parse_sum(Before::Open)?;
+				let close = self.next_span();
+				return match self.next().map(|token| token.kind) {
+					Some(TokenKind::Close) => Ok(Expr {
+						span: span.to(close),
+						kind: ExprKind::Group(Box::new(e)),
+					}),
+					_ => Err(ParseError::new(ImpossibleOpen, span)),
+				};
 			}
-			'/' => {
-				self.push_num()?;
-				self.push_bin_operator(Ops::Div)?;
-				self.is_factor = false;
-				self.unary_min = true;
+			Some(TokenKind::Op(op)) => {
+				let error = match before {
+					Before::Minus => UnaryMinusFailed(op),
+					_ => BinaryOperatorZeroOperand(op),
+				};
+				return Err(ParseError::new(error, span));
 			}
-			'^' => {
-				self.push_num()?;
-				self.push_bin_operator(Ops::Pow)?;
-				self.is_factor = false;
-				self.unary_min = true;
+			token => {
+				let error = match (before, token) {
+					(Before::Start, None) => EmptyStringInput,
+					(Before::Open, None) => ImpossibleOpen,
+					(Before::Start | Before::Open, Some(_)) => ImpossibleClose,
+					(Before::Operator(op), _) => BinaryOperatorOneOperand(op),
+					(Before::Minus, _) => BinaryOperatorOneOperand(BinaryOp::Sub),
+				};
+				return Err(ParseError::new(error, span));
 			}
-			'X' | 'x' => {
-				self.push_num()?;
-				if self.is_factor {
-					self.push_bin_operator(Ops::Mul)?;
-				}
-				self.is_factor = true;
-				self.unary_min = false;
-				if self.is_min {
-					// In that case we have an expression like -X, which should only be seen at the
-					// beginning of an expression
-					self.is_min = false;
-					self.pols_vec.push(polynomial![T::zero() - T::one()]);
-					self.push_bin_operator(Ops::Mul)?;
-				}
-				self.pols_vec.push(X);
-			}
-			'(' => {
-				self.push_num()?;
-				if self.is_factor {
-					self.push_bin_operator(Ops::Mul)?;
-				}
-				self.ops_vec.push(Ops::Open);
-				if self.is_min {
-					// The idea is to turning any (...) into ((...)) and -(...) into (-1 * (...))
-					self.is_min = false;
-					self.pols_vec.push(polynomial![T::zero() - T::one()]);
-					self.push_bin_operator(Ops::Mul)?;
+		};
+		Ok(Expr { kind, span })
+	}
+}
+
+/// The iterator of `Polynomial::parse_many_with`, which reads the lines in a
+/// buffer reused for all of them and parses their statements in place.
+struct ParseMany<R, T> {
+	reader: R,
+	options: ParserOptions,
+	environment: Environment<T>,
+	line: String,
+	line_number: usize,
+	/// The offset in `line` of the next statement.
+	position: usize,
+	done: bool,
+}
+
+impl<R, T> Iterator for ParseMany<R, T>
+where
+	R: BufRead,
+	T: CoefficientLiteral,
+{
+	type Item = Result<Polynomial<T>, ParseError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if self.position >= self.line.len() {
+				if self.done {
+					return None;
 				}
-				self.ops_vec.push(Ops::Open);
-				self.is_factor = false;
-				self.unary_min = true;
-			}
-			')' => {
-				self.push_num()?;
-				// Doing it twice because there always are two layers of parenthesis
-				for _ in 0..2 {
-					while !self.ops_vec.is_empty()
-						&& self.ops_vec[self.ops_vec.len() - 1] != Ops::Open
-					{
-						self.execute_bin_operator()?;
+				self.line.clear();
+				self.position = 0;
+				match self.reader.read_line(&mut self.line) {
+					Ok(0) => self.done = true,
+					Ok(_) => {
+						self.line_number += 1;
+						let length = self.line.trim_end_matches(['\n', '\r']).len();
+						self.line.truncate(length);
 					}
-					if self.ops_vec.is_empty() || self.ops_vec.pop() != Some(Ops::Open) {
-						return Err(ImpossibleClose);
+					Err(e) => {
+						self.done = true;
+						self.line.clear();
+						let mut error = ParseError::new(ReadFailed(e.to_string()), Span::default());
+						error.location = Some((self.line_number + 1, 1));
+						return Some(Err(error));
 					}
 				}
-				self.is_min = false;
-				self.is_factor = true;
-				self.unary_min = false;
-			}
-			'.' => {
-				self.reads_num = true;
-				self.reads_dec = true;
-				self.unary_min = false;
-			}
-			',' => {
-				self.reads_num = true;
-				self.reads_dec = true;
-				self.unary_min = false;
-			}
-			'0' => self.read_digit(0),
-			'1' => self.read_digit(1),
-			'2' => self.read_digit(2),
-			'3' => self.read_digit(3),
-			'4' => self.read_digit(4),
-			'5' => self.read_digit(5),
-			'6' => self.read_digit(6),
-			'7' => self.read_digit(7),
-			'8' => self.read_digit(8),
-			'9' => self.read_digit(9),
-			'⁰' => {
-				self.read_char('^')?;
-				self.read_digit(0);
-			}
-			'¹' => {
-				self.read_char('^')?;
-				self.read_digit(1);
-			}
-			'²' => {
-				self.read_char('^')?;
-				self.read_digit(2);
-			}
-			'³' => {
-				self.read_char('^')?;
-				self.read_digit(3);
-			}
-			'⁴' => {
-				self.read_char('^')?;
-				self.read_digit(4);
-			}
-			'⁵' => {
-				self.read_char('^')?;
-				self.read_digit(5);
+				continue;
 			}
-			'⁶' => {
-				self.read_char('^')?;
-				self.read_digit(6);
+			let start = self.position;
+			let end = self.line[start..]
+				.find(';')
+				.map_or(self.line.len(), |i| start + i);
+			self.position = end + 1;
+			let statement = &self.line[start..end];
+			if statement.trim().is_empty() {
+				continue;
 			}
-			'⁷' => {
-				self.read_char('^')?;
-				self.read_digit(7);
-			}
-			'⁸' => {
-				self.read_char('^')?;
-				self.read_digit(8);
-			}
-			'⁹' => {
-				self.read_char('^')?;
-				self.read_digit(9);
-			}
-			_ => return Err(UnsupportedCharacter(c)),
-		};
-		Ok(())
+			let result = parse_expr_with(statement, &self.options)
+				.and_then(|expr| expr.eval_in(&self.environment));
+			return Some(result.map_err(|mut error| {
+				error.span = Span {
+					start: start + error.span.start,
+					end: start + error.span.end,
+				};
+				let column = self.line[..error.span.start].chars().count() + 1;
+				error.location = Some((self.line_number, column));
+				error
+			}));
+		}
 	}
 }
 
 impl<T> Polynomial<T>
 where
-	T: Primitive,
+	T: CoefficientLiteral,
 {
-	fn parse_string_checked(s: String) -> Result<Self, PolynomialError> {
-		let mut parser = Parser::<T> {
-			pols_vec: Vec::new(),
-			ops_vec: Vec::new(),
-			reads_num: false,
-			reads_dec: false,
-			num: 0u64,
-			nb_decs: 0u32,
-			unary_min: true,
-			is_min: false,
-			is_factor: false,
-		};
-		let s_chars: Vec<char> = s.chars().collect();
-		for c in s_chars {
-			parser.read_char(c)?;
-		}
-		parser.push_num()?;
-		while !parser.ops_vec.is_empty() {
-			parser.execute_bin_operator()?;
-		}
-		parser.pols_vec.pop().ok_or(EmptyStringInput)
+	fn parse_string_checked(
+		s: &str,
+		options: &ParserOptions,
+		environment: &Environment<T>,
+	) -> Result<Self, PolynomialError> {
+		parse_expr_with(s, options)
+			.and_then(|expr| expr.eval_in(environment))
+			.map_err(|e| e.error)
 	}
 
 	/// Parses a string and returns a `Result` containing either a `Polynomial`
 	/// object or an error message.
 	///
+	/// The same grammar is used for every coefficient type implementing
+	/// `CoefficientLiteral`: literals are converted exactly to integers,
+	/// rationals or finite field elements, and `i` or `j` denote the imaginary
+	/// unit for complex coefficients. See `expr::parse_expr` to keep the parsed
+	/// expression.
+	///
+	/// Literals may use scientific notation (`6.02e23`), group their digits
+	/// with underscores (`1_000_000`) and be written in hexadecimal (`0x1F`)
+	/// or binary (`0b101`) when they are integers.
+	///
 	/// # Arguments
 	///
 	/// * `s` - A `String` containing the string to be parsed.
@@ -380,11 +613,93 @@ where
 	///   was successful.
 	/// * `Err(String)` - A `Result` containing an error message if the parsing
 	///   failed.
-
+	///
+	/// # Example
+	/// ```rust
+	/// use num::Complex;
+	/// use polyx::{fields::Fp, *};
+	/// let p = Polynomial::<Complex<f64>>::parse_string("(X - i)(X + i)".to_string()).unwrap();
+	/// assert_eq!(p, polynomial![Complex::from(1.), Complex::from(0.), Complex::from(1.)]);
+	///
+	/// let p = Polynomial::<Fp<5>>::parse_string("(X + 1)^5".to_string()).unwrap();
+	/// assert_eq!(p, Polynomial::<Fp<5>>::parse_string("X^5 + 1".to_string()).unwrap());
+	/// ```
 	pub fn parse_string(s: String) -> Result<Self, String> {
-		match Self::parse_string_checked(s) {
+		Self::parse_string_with(&s, &ParserOptions::default())
+	}
+
+	/// Parses a string with the given options, e.g. another name for the
+	/// indeterminate, see `parse_string`.
+	pub fn parse_string_with(s: &str, options: &ParserOptions) -> Result<Self, String> {
+		match Self::parse_string_checked(s, options, &Environment::new()) {
 			Ok(p) => Ok(p),
 			Err(e) => Err(e.to_string()),
 		}
 	}
+
+	/// Parses a string whose parameters and functions are bound in an
+	/// environment, see `Environment`. Unbound names are reported together in
+	/// the error.
+	pub fn parse_string_in(s: &str, environment: &Environment<T>) -> Result<Self, String> {
+		match Self::parse_string_checked(s, &ParserOptions::default(), environment) {
+			Ok(p) => Ok(p),
+			Err(e) => Err(e.to_string()),
+		}
+	}
+
+	/// Parses polynomials from a reader, one per line or separated by `;`.
+	/// Blank lines and empty statements are skipped.
+	///
+	/// The lines are read lazily into a single buffer. The errors give the
+	/// line and the column where they occurred, and their span is relative to
+	/// the line. Parsing goes on after an invalid polynomial, but stops after
+	/// an error of the reader, which is returned as a `ReadFailed` error.
+	/// Statements nested too deeply or of too high a degree are reported as
+	/// `NestingTooDeep` or `PowerOverflow` errors, see `expr::Expr::eval`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let source = "X^2 - 1; 2X + 1\n\n(X + 1)^2; X +\n";
+	/// let polynomials: Vec<_> = Polynomial::<i32>::parse_many(source.as_bytes()).collect();
+	/// assert_eq!(polynomials[0], Ok(polynomial![-1, 0, 1]));
+	/// assert_eq!(polynomials[1], Ok(polynomial![1, 2]));
+	/// assert_eq!(polynomials[2], Ok(polynomial![1, 2, 1]));
+	/// let error = polynomials[3].as_ref().unwrap_err();
+	/// assert_eq!((error.line(), error.column()), (Some(3), Some(15)));
+	/// assert_eq!(error.to_string(), "BinaryOperatorOneOperand('+') at line 3, column 15");
+	/// ```
+	pub fn parse_many<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Self, ParseError>> {
+		Self::parse_many_with(reader, &ParserOptions::default())
+	}
+
+	/// Parses polynomials from a reader with the given options, see
+	/// `parse_many`.
+	pub fn parse_many_with<R: BufRead>(
+		reader: R,
+		options: &ParserOptions,
+	) -> impl Iterator<Item = Result<Self, ParseError>> {
+		ParseMany {
+			reader,
+			options: options.clone(),
+			environment: Environment::new(),
+			line: String::new(),
+			line_number: 0,
+			position: 0,
+			done: false,
+		}
+	}
+}
+
+impl<T> FromStr for Polynomial<T>
+where
+	T: CoefficientLiteral,
+{
+	type Err = ParseError;
+
+	/// Parses a polynomial like `parse_string`, keeping the span of the error.
+	/// This is the inverse of `Polynomial::canonical`.
+	fn from_str(s: &str) -> Result<Self, ParseError> {
+		parse_expr_with(s, &ParserOptions::default())?.eval()
+	}
 }
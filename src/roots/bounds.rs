@@ -0,0 +1,178 @@
+use num_traits::Zero;
+
+use super::RootBound;
+use crate::{traits::HasNorm, Polynomial};
+
+impl<T> Polynomial<T>
+where
+	T: Clone + Zero + HasNorm,
+{
+	/// Returns an upper bound on the moduli of the complex roots of the
+	/// polynomial. The bound of a non-zero constant polynomial is 0.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{roots::RootBound, *};
+	/// // (X - 1)(X - 2)(X + 3)
+	/// let p = polynomial![6., -7., 0., 1.];
+	/// assert_eq!(p.root_upper_bound(RootBound::Cauchy), 8.);
+	/// assert_eq!(p.root_upper_bound(RootBound::Lagrange), 13.);
+	/// assert!(p.root_upper_bound(RootBound::Fujiwara) >= 3.);
+	/// assert!(p.root_upper_bound(RootBound::Kojima).is_infinite());
+	/// ```
+	pub fn root_upper_bound(&self, bound: RootBound) -> f64 {
+		if self.is_empty() {
+			panic!("Every complex number is a root of the zero polynomial");
+		}
+		let n = self.degree();
+		if n == 0 {
+			return 0.;
+		}
+		let lc = self[n].norm();
+		let ratios = self.0[..n].iter().map(|a| a.norm() / lc);
+		match bound {
+			RootBound::Cauchy => 1. + ratios.fold(0., f64::max),
+			RootBound::Lagrange => ratios.sum::<f64>().max(1.),
+			RootBound::Fujiwara => {
+				2. * ratios
+					.enumerate()
+					.map(|(i, r)| {
+						if i == 0 {
+							(0.5 * r).powf(1. / n as f64)
+						} else {
+							r.powf(1. / (n - i) as f64)
+						}
+					})
+					.fold(0., f64::max)
+			}
+			RootBound::Kojima => {
+				if self.0[1..n].iter().any(|a| a.norm() == 0.) {
+					return f64::INFINITY;
+				}
+				2. * (1..=n)
+					.map(|i| {
+						let r = self[i - 1].norm() / self[i].norm();
+						if i == 1 {
+							0.5 * r
+						} else {
+							r
+						}
+					})
+					.fold(0., f64::max)
+			}
+		}
+	}
+
+	/// Returns a lower bound on the moduli of the complex roots of the
+	/// polynomial, computed as the inverse of the upper bound of `self.rev()`.
+	/// The bound is 0 when 0 is a root, and infinite for a non-zero constant
+	/// polynomial.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::{roots::RootBound, *};
+	/// // (X - 1)(X - 2)(X + 3)
+	/// let p = polynomial![6., -7., 0., 1.];
+	/// assert!(p.root_lower_bound(RootBound::Cauchy) <= 1.);
+	/// assert_eq!(polynomial![0., 1., 1.].root_lower_bound(RootBound::Cauchy), 0.);
+	/// ```
+	pub fn root_lower_bound(&self, bound: RootBound) -> f64 {
+		if self.is_empty() {
+			panic!("Every complex number is a root of the zero polynomial");
+		}
+		if self.degree() == 0 {
+			return f64::INFINITY;
+		}
+		if self[0].norm() == 0. {
+			return 0.;
+		}
+		1. / self.rev().root_upper_bound(bound)
+	}
+}
+
+impl Polynomial<f64> {
+	/// Tells whether all the roots of the polynomial lie in the open unit disk,
+	/// using the Schur-Cohn test. Non-zero constant polynomials are stable.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// // (X - 0.5)(X + 0.8)
+	/// assert!(polynomial![-0.4, 0.3, 1.].is_schur_stable());
+	/// // (X - 0.5)(X + 1.2)
+	/// assert!(!polynomial![-0.6, 0.7, 1.].is_schur_stable());
+	/// ```
+	pub fn is_schur_stable(&self) -> bool {
+		if self.is_empty() {
+			panic!("Every complex number is a root of the zero polynomial");
+		}
+		let mut p = self.clone();
+		while p.degree() > 0 {
+			let (a0, an) = (p[0], p[p.degree()]);
+			if a0.abs() >= an.abs() {
+				return false;
+			}
+			// Schur transform: (a_n p - a_0 rev(p)) / X has one degree less and the same
+			// number of roots outside the unit disk
+			let q: Vec<f64> = (1..=p.degree())
+				.map(|i| an * p[i] - a0 * p[p.degree() - i])
+				.collect();
+			p = Polynomial(q);
+		}
+		true
+	}
+
+	/// Tells whether all the roots of the polynomial have a negative real part,
+	/// using the Routh-Hurwitz criterion. Non-zero constant polynomials are
+	/// stable.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// // (X + 1)(X^2 + X + 1)
+	/// assert!(polynomial![1., 2., 2., 1.].is_hurwitz_stable());
+	/// // X^2 + 1
+	/// assert!(!polynomial![1., 0., 1.].is_hurwitz_stable());
+	/// ```
+	pub fn is_hurwitz_stable(&self) -> bool {
+		if self.is_empty() {
+			panic!("Every complex number is a root of the zero polynomial");
+		}
+		let n = self.degree();
+		let lc = self[n];
+		// The two first rows of the Routh array
+		let mut upper: Vec<f64> = (0..=n).rev().step_by(2).map(|i| self[i]).collect();
+		let mut lower: Vec<f64> = (0..n).rev().step_by(2).map(|i| self[i]).collect();
+		for _ in 0..n {
+			let pivot = lower[0];
+			if pivot == 0. || (pivot < 0.) != (lc < 0.) {
+				return false;
+			}
+			let next: Vec<f64> = (0..(upper.len() - 1))
+				.map(|j| {
+					let below = lower.get(j + 1).copied().unwrap_or(0.);
+					(pivot * upper[j + 1] - upper[0] * below) / pivot
+				})
+				.collect();
+			upper = lower;
+			lower = if next.is_empty() { vec![0.] } else { next };
+		}
+		true
+	}
+}
@@ -0,0 +1,180 @@
+use num::complex::Complex;
+use num_traits::Zero;
+
+use crate::{traits::FloatLike, Polynomial};
+
+/// Maximal number of QR sweeps allowed per eigenvalue before giving up.
+const MAX_SWEEPS: usize = 60;
+
+impl<T> Polynomial<T>
+where
+	T: FloatLike,
+{
+	/// Returns the companion matrix of the polynomial after normalizing it to be
+	/// monic, as a vector of rows.
+	///
+	/// For `p = X^n + a_{n-1} X^{n-1} + ... + a_0`, the matrix has ones on its
+	/// subdiagonal and `-a_0, ..., -a_{n-1}` on its last column, so it is upper
+	/// Hessenberg and its characteristic polynomial is `p`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let p = polynomial![6., -5., 1.];
+	/// assert_eq!(
+	///   p.companion_matrix(),
+	///   vec![vec![0., -6.], vec![1., 5.]]
+	/// );
+	/// ```
+	pub fn companion_matrix(&self) -> Vec<Vec<T>> {
+		if self.is_zero() {
+			panic!("The zero polynomial has no companion matrix");
+		}
+		let n = self.degree();
+		let lc_inv = self[n].clone().inv();
+		let mut matrix = vec![vec![T::zero(); n]; n];
+		for i in 0..n {
+			if i > 0 {
+				matrix[i][i - 1] = T::one();
+			}
+			matrix[i][n - 1] = T::zero() - self[i].clone() * lc_inv.clone();
+		}
+		matrix
+	}
+}
+
+/// Computes the eigenvalues of an upper Hessenberg matrix with the shifted QR
+/// algorithm. Each sweep uses Givens rotations and a Wilkinson shift, and
+/// eigenvalues are deflated from the bottom of the matrix as soon as the
+/// corresponding subdiagonal coefficient becomes negligible.
+#[allow(clippy::needless_range_loop)]
+fn hessenberg_eigenvalues(mut h: Vec<Vec<Complex<f64>>>) -> Vec<Complex<f64>> {
+	let mut eigenvalues = Vec::with_capacity(h.len());
+	let mut hi = h.len();
+	let mut sweeps = 0;
+	while hi > 0 {
+		if hi == 1 {
+			eigenvalues.push(h[0][0]);
+			break;
+		}
+		// Looks for the bottom of the active block, deflating negligible coefficients
+		let mut lo = hi - 1;
+		while lo > 0 {
+			let scale = h[lo][lo].norm() + h[lo - 1][lo - 1].norm();
+			if h[lo][lo - 1].norm() <= f64::EPSILON * scale {
+				h[lo][lo - 1] = Complex::zero();
+				break;
+			}
+			lo -= 1;
+		}
+		if lo == hi - 1 {
+			eigenvalues.push(h[hi - 1][hi - 1]);
+			hi -= 1;
+			sweeps = 0;
+			continue;
+		}
+		sweeps += 1;
+		if sweeps > MAX_SWEEPS {
+			panic!("The QR algorithm did not converge after {MAX_SWEEPS} sweeps");
+		}
+
+		// Wilkinson shift, with an exceptional shift from time to time to break cycles
+		let (a, b) = (h[hi - 2][hi - 2], h[hi - 2][hi - 1]);
+		let (c, d) = (h[hi - 1][hi - 2], h[hi - 1][hi - 1]);
+		let mu = if sweeps % 10 == 0 {
+			d + Complex::new(c.norm(), c.norm())
+		} else {
+			let half_diff = (a - d) * 0.5;
+			let disc = (half_diff * half_diff + b * c).sqrt();
+			let (mu1, mu2) = (d + half_diff + disc, d + half_diff - disc);
+			if (mu1 - d).norm() <= (mu2 - d).norm() {
+				mu1
+			} else {
+				mu2
+			}
+		};
+
+		for k in lo..hi {
+			h[k][k] -= mu;
+		}
+		// QR factorization of the shifted active block with Givens rotations
+		let mut rotations = Vec::with_capacity(hi - lo - 1);
+		for k in lo..(hi - 1) {
+			let (x, y) = (h[k][k], h[k + 1][k]);
+			let r = (x.norm_sqr() + y.norm_sqr()).sqrt();
+			let (cos, sin) = if r == 0. {
+				(Complex::from(1.), Complex::zero())
+			} else {
+				(x / r, y / r)
+			};
+			for j in k..hi {
+				let (u, v) = (h[k][j], h[k + 1][j]);
+				h[k][j] = cos.conj() * u + sin.conj() * v;
+				h[k + 1][j] = cos * v - sin * u;
+			}
+			rotations.push((cos, sin));
+		}
+		// Computes RQ, which is similar to the shifted active block
+		for (k, (cos, sin)) in (lo..(hi - 1)).zip(rotations) {
+			for row in h.iter_mut().take((k + 2).min(hi)).skip(lo) {
+				let (u, v) = (row[k], row[k + 1]);
+				row[k] = u * cos + v * sin;
+				row[k + 1] = v * cos.conj() - u * sin.conj();
+			}
+		}
+		for k in lo..hi {
+			h[k][k] += mu;
+		}
+	}
+	eigenvalues
+}
+
+impl Polynomial<f64> {
+	/// Computes all the complex roots of the polynomial as the eigenvalues of
+	/// its companion matrix, using a self-contained QR algorithm.
+	///
+	/// The roots are returned in no particular order, with multiplicity.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero or if the QR
+	/// algorithm fails to converge.
+	///
+	/// # Example
+	/// ```rust
+	/// use num::Complex;
+	/// use polyx::*;
+	/// let p = polynomial![1., 0., 1.];
+	/// let mut roots = p.roots_via_companion();
+	/// roots.sort_by(|z1, z2| z1.im.partial_cmp(&z2.im).unwrap());
+	/// assert!((roots[0] - Complex::new(0., -1.)).norm() < 1e-9);
+	/// assert!((roots[1] - Complex::new(0., 1.)).norm() < 1e-9);
+	/// ```
+	pub fn roots_via_companion(&self) -> Vec<Complex<f64>> {
+		hessenberg_eigenvalues(
+			self.companion_matrix()
+				.into_iter()
+				.map(|row| row.into_iter().map(Complex::from).collect())
+				.collect(),
+		)
+	}
+}
+
+impl Polynomial<Complex<f64>> {
+	/// Computes all the roots of the polynomial as the eigenvalues of its
+	/// companion matrix, using a self-contained QR algorithm.
+	///
+	/// The roots are returned in no particular order, with multiplicity.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the polynomial is zero or if the QR
+	/// algorithm fails to converge.
+	pub fn roots_via_companion(&self) -> Vec<Complex<f64>> {
+		hessenberg_eigenvalues(self.companion_matrix())
+	}
+}
@@ -0,0 +1,34 @@
+//! Numerical tools to locate and approximate the roots of a polynomial.
+
+mod bounds;
+mod companion;
+mod refine;
+
+/// The outcome of a root refinement, as returned by
+/// [`Polynomial::refine_root`](crate::Polynomial::refine_root).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootRefinement {
+	/// The approximation of the root.
+	pub root: f64,
+	/// The number of iterations that were needed to reach the tolerance.
+	pub iterations: usize,
+	/// The final interval `(a, b)` known to contain the root.
+	pub bracket: (f64, f64),
+}
+
+/// The classical upper bounds on the moduli of the roots of a polynomial
+/// `a_n X^n + ... + a_0`, as used by
+/// [`Polynomial::root_upper_bound`](crate::Polynomial::root_upper_bound) and
+/// [`Polynomial::root_lower_bound`](crate::Polynomial::root_lower_bound).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootBound {
+	/// `1 + max |a_i / a_n|`.
+	Cauchy,
+	/// `2 max |a_{n-k} / a_n|^(1/k)`, the last term being `|a_0 / (2 a_n)|^(1/n)`.
+	Fujiwara,
+	/// `max(1, sum |a_i / a_n|)`.
+	Lagrange,
+	/// `2 max |a_{i-1} / a_i|`, the last term being `|a_0 / (2 a_1)|`. It only
+	/// applies when `a_1, ..., a_{n-1}` are non-zero and is infinite otherwise.
+	Kojima,
+}
@@ -0,0 +1,111 @@
+use super::RootRefinement;
+use crate::Polynomial;
+
+impl Polynomial<f64> {
+	/// Refines a root isolated in the interval `[a, b]` until it is bracketed
+	/// by an interval of width at most `tol`.
+	///
+	/// The method is a safeguarded Newton iteration: Newton steps are taken
+	/// whenever they stay inside the current bracket and shrink fast enough,
+	/// and a bisection step is taken otherwise. The polynomial and its
+	/// derivative are evaluated together with `eval_with_derivative`. Newton
+	/// steps smaller than `tol / 2` are lengthened to `tol / 2` so that the
+	/// root ends up bracketed from both sides.
+	///
+	/// # Arguments
+	///
+	/// * `a`, `b` - The bounds of the isolating interval, in any order.
+	/// * `tol` - The maximal width of the final bracket.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `tol` is not positive, if `a` or `b` is not
+	/// finite, or if the polynomial has the same strict sign at `a` and `b`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let p = polynomial![-2., 0., 1.];
+	/// let r = p.refine_root(0., 2., 1e-12);
+	/// assert!((r.root - 2f64.sqrt()).abs() <= 1e-12);
+	/// assert!(r.bracket.1 - r.bracket.0 <= 1e-12);
+	/// ```
+	pub fn refine_root(&self, a: f64, b: f64, tol: f64) -> RootRefinement {
+		if tol.is_nan() || tol <= 0. {
+			panic!("The tolerance of a root refinement must be positive, got {tol}");
+		}
+		if !a.is_finite() || !b.is_finite() {
+			panic!("Cannot refine a root in the non-finite interval [{a}, {b}]");
+		}
+		let (mut lo, mut hi) = if a <= b { (a, b) } else { (b, a) };
+		let mut f_lo = self.eval(lo);
+		let mut f_hi = self.eval(hi);
+		if f_lo == 0. || f_hi == 0. {
+			let root = if f_lo == 0. { lo } else { hi };
+			return RootRefinement {
+				root,
+				iterations: 0,
+				bracket: (root, root),
+			};
+		}
+		if (f_lo < 0.) == (f_hi < 0.) {
+			panic!(
+				"The interval [{lo}, {hi}] does not bracket a root: the polynomial has the same sign \
+				 at both ends"
+			);
+		}
+
+		let mut x = 0.5 * (lo + hi);
+		let (mut fx, mut dfx) = self.eval_with_derivative(x);
+		let mut iterations = 1;
+		// The steps taken during the last two iterations
+		let mut step = hi - lo;
+		let mut step_old;
+		loop {
+			if fx == 0. {
+				(lo, hi, f_lo, f_hi) = (x, x, fx, fx);
+				break;
+			}
+			if (fx < 0.) == (f_lo < 0.) {
+				(lo, f_lo) = (x, fx);
+			} else {
+				(hi, f_hi) = (x, fx);
+			}
+			if hi - lo <= tol {
+				break;
+			}
+
+			step_old = step;
+			let newton = x - fx / dfx;
+			let next = if newton > lo && newton < hi && (2. * fx).abs() <= (step_old * dfx).abs() {
+				step = newton - x;
+				if step.abs() < 0.5 * tol {
+					// Overshoots slightly so that the root gets bracketed on both sides
+					step = 0.5 * tol * step.signum();
+				}
+				if x + step > lo && x + step < hi {
+					x + step
+				} else {
+					0.5 * (lo + hi)
+				}
+			} else {
+				0.5 * (lo + hi)
+			};
+			step = next - x;
+			if next <= lo || next >= hi {
+				// The bracket cannot be shrunk any further in floating point arithmetic
+				break;
+			}
+
+			x = next;
+			(fx, dfx) = self.eval_with_derivative(x);
+			iterations += 1;
+		}
+
+		RootRefinement {
+			root: if f_lo.abs() <= f_hi.abs() { lo } else { hi },
+			iterations,
+			bracket: (lo, hi),
+		}
+	}
+}
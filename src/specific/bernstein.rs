@@ -1,45 +1,95 @@
-use std::fmt::Debug;
-
 use num_traits::{PrimInt, Zero};
 
-use crate::{traits::HasNorm, Polynomial};
+use crate::{
+	traits::{CoefficientLiteral, PolyxNum},
+	Polynomial,
+};
 
-fn binom<T: PrimInt, U1: PrimInt, U2: PrimInt>(_n: U1, _k: U2) -> T {
-	let n: usize = _n.to_usize().unwrap();
-	let k: usize = _k.to_usize().unwrap();
+/// Computes the binomial coefficient `C(n, k)` in `T` with Pascal's rule, so
+/// that it only needs additions and is also correct in a field of positive
+/// characteristic. Every intermediate value is itself a binomial coefficient,
+/// so that nothing overflows before the result does.
+fn binom<T: PolyxNum>(n: usize, k: usize) -> T {
 	if k > n {
-		T::from(0).unwrap()
-	} else {
-		let mut b: usize = 1;
-		for j in 0..k {
-			b *= n - j;
-		}
-		for j in 1..=k {
-			b /= j;
+		return T::zero();
+	}
+	let k = k.min(n - k);
+	binomials(n, k, |a: &T, b: &T| Some(a.clone() + b.clone()))
+		.unwrap()
+		.swap_remove(k)
+}
+
+/// Computes the binomial coefficients `C(n, 0)` to `C(n, k)`, with `k <= n`,
+/// by Pascal's rule with the addition `sum`, which returns `None` on overflow.
+fn binomials<T: PolyxNum>(n: usize, k: usize, sum: impl Fn(&T, &T) -> Option<T>) -> Option<Vec<T>> {
+	let mut row = vec![T::zero(); k + 1];
+	row[0] = T::one();
+	for i in 1..=n {
+		for j in (1..=k.min(i)).rev() {
+			row[j] = sum(&row[j], &row[j - 1])?;
 		}
-		T::from(b).unwrap()
 	}
+	Some(row)
 }
 
 impl<T> Polynomial<T>
 where
-	T: Debug + PrimInt + HasNorm,
+	T: PolyxNum,
 {
 	/// Computes the Bernstein polynomial of degree `m` and index `i` for a given
 	/// (unsigned) integer type `U`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// // 3 X (1 - X)^2
+	/// assert_eq!(Polynomial::<i32>::bernstein(3, 1), polynomial![0, 3, -6, 3]);
+	/// ```
 	pub fn bernstein<U: PrimInt>(_m: U, _i: U) -> Self {
 		let m: usize = _m.to_usize().unwrap();
 		let i: usize = _i.to_usize().unwrap();
 		if i > m {
 			Self::zero()
 		} else {
-			let b = T::from(binom::<usize, _, _>(m, i)).unwrap();
+			let b: T = binom(m, i);
 			crate::polynomial![T::one(), T::zero() - T::one()]
 				.powi(m - i)
 				.into_iter()
-				.map(|x| x * b)
+				.map(|x| x * b.clone())
 				.collect::<Polynomial<T>>()
 				* crate::polynomial![T::zero(), T::one()].powi(i)
 		}
 	}
 }
+
+impl<T> Polynomial<T>
+where
+	T: CoefficientLiteral,
+{
+	/// Same as `bernstein`, but returns `None` instead of overflowing the
+	/// coefficient type, as used by `bernstein(m, i)` in expressions.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// assert_eq!(Polynomial::<i64>::checked_bernstein(3, 1), Some(polynomial![0, 3, -6, 3]));
+	/// assert_eq!(Polynomial::<i64>::checked_bernstein(70, 35), None);
+	/// ```
+	pub fn checked_bernstein(m: usize, i: usize) -> Option<Self> {
+		if i > m {
+			return Some(Self::zero());
+		}
+		let b = binomials(m, i.min(m - i), T::checked_sum)?.swap_remove(i.min(m - i));
+		// The coefficient of X^(i + k) is (-1)^k C(m, i) C(m - i, k)
+		let mut coefficients = vec![T::zero(); i];
+		for (k, c) in binomials(m - i, m - i, T::checked_sum)?.iter().enumerate() {
+			let c = b.checked_product(c)?;
+			coefficients.push(if k % 2 == 0 {
+				c
+			} else {
+				T::zero().checked_difference(&c)?
+			});
+		}
+		Some(Self::from(coefficients))
+	}
+}
@@ -0,0 +1,69 @@
+use num_traits::PrimInt;
+
+use crate::{
+	polynomial,
+	traits::{CoefficientLiteral, PolyxNum},
+	Polynomial,
+};
+
+impl<T> Polynomial<T>
+where
+	T: PolyxNum,
+{
+	/// Computes the Chebyshev polynomial of the first kind `T_n`, such that
+	/// `T_n(cos θ) = cos(nθ)`, with the recurrence
+	/// `T_(n+1) = 2X T_n - T_(n-1)`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// // 4X^3 - 3X
+	/// assert_eq!(Polynomial::<i32>::chebyshev(3), polynomial![0, -3, 0, 4]);
+	/// ```
+	pub fn chebyshev<U: PrimInt>(n: U) -> Self {
+		let n: usize = n.to_usize().unwrap();
+		let two_x = polynomial![T::zero(), T::one() + T::one()];
+		let (mut previous, mut current) = (polynomial![T::one()], polynomial![T::zero(), T::one()]);
+		if n == 0 {
+			return previous;
+		}
+		for _ in 1..n {
+			let next = &two_x * &current - previous;
+			previous = std::mem::replace(&mut current, next);
+		}
+		current
+	}
+}
+
+impl<T> Polynomial<T>
+where
+	T: CoefficientLiteral,
+{
+	/// Same as `chebyshev`, but returns `None` instead of overflowing the
+	/// coefficient type, as used by `chebyshev(n)` in expressions.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// assert_eq!(Polynomial::<i64>::checked_chebyshev(3), Some(polynomial![0, -3, 0, 4]));
+	/// assert_eq!(Polynomial::<i64>::checked_chebyshev(64), None);
+	/// ```
+	pub fn checked_chebyshev(n: usize) -> Option<Self> {
+		let two = T::one().checked_sum(&T::one())?;
+		let (mut previous, mut current) = (vec![T::one()], vec![T::zero(), T::one()]);
+		if n == 0 {
+			return Some(Self::from(previous));
+		}
+		for _ in 1..n {
+			let mut next = vec![T::zero()];
+			for c in &current {
+				next.push(two.checked_product(c)?);
+			}
+			for (x, c) in next.iter_mut().zip(&previous) {
+				*x = x.checked_difference(c)?;
+			}
+			previous = std::mem::replace(&mut current, next);
+		}
+		Some(Self::from(current))
+	}
+}
@@ -1,2 +1,3 @@
 mod bernstein;
+mod chebyshev;
 mod lagrange;
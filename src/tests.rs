@@ -59,3 +59,1594 @@ fn test_latex() {
 		.unwrap();
 	println!("{}", p.to_latex());
 }
+
+#[test]
+fn test_latex_exact() {
+	use num::{BigInt, BigRational};
+
+	// (10^400 / 7) X + 1/3 does not fit in an f64
+	let big = num_traits::pow(BigInt::from(10), 400);
+	let p = polynomial![
+		BigRational::new(BigInt::from(1), BigInt::from(3)),
+		BigRational::new(big.clone(), BigInt::from(7))
+	];
+	assert_eq!(
+		p.to_latex(),
+		format!("\\frac{{{big}}}{{7}}\\ X+\\frac{{1}}{{3}}")
+	);
+
+	let p = polynomial![-big.clone(), BigInt::from(0), BigInt::from(2)];
+	assert_eq!(p.to_latex(), format!("2\\ X^{{2}}-{big}"));
+
+	let p = polynomial![
+		Complex::new(BigInt::from(0), BigInt::from(0)),
+		Complex::new(-big.clone(), -BigInt::from(1))
+	];
+	assert_eq!(p.to_latex(), format!("-({big}+1i)\\ X"));
+}
+
+#[test]
+fn test_refine_root() {
+	// (X - 1)(X - 2)(X - 3)
+	let p = polynomial![-6., 11., -6., 1.];
+	for (a, b, root) in [(0.5, 1.5, 1.), (1.8, 2.7, 2.), (2.5, 10., 3.)] {
+		let r = p.refine_root(a, b, 1e-10);
+		assert!(r.bracket.0 <= root && root <= r.bracket.1);
+		assert!(r.bracket.1 - r.bracket.0 <= 1e-10);
+		assert!((r.root - root).abs() <= 1e-10);
+	}
+	let r = p.refine_root(0., 1., 1e-10);
+	assert_eq!((r.root, r.iterations, r.bracket), (1., 0, (1., 1.)));
+}
+
+#[test]
+fn test_roots_via_companion() {
+	// (X - 1)(X - 2)(X - 3)(X^2 + 4)
+	let p = polynomial![-6., 11., -6., 1.] * polynomial![4., 0., 1.];
+	let roots = p.roots_via_companion();
+	assert_eq!(roots.len(), 5);
+	for z in &roots {
+		assert!(p.eval(z.re).abs() < 1e-6 || (z.norm() - 2.).abs() < 1e-9);
+	}
+	let mut real_roots: Vec<f64> = roots
+		.iter()
+		.filter(|z| z.im.abs() < 1e-9)
+		.map(|z| z.re)
+		.collect();
+	real_roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+	for (z, (a, b)) in real_roots.iter().zip([(0.5, 1.5), (1.8, 2.7), (2.5, 10.)]) {
+		assert!((z - p.refine_root(a, b, 1e-12).root).abs() < 1e-9);
+	}
+
+	let p =
+		Polynomial::<Complex<f64>>::parse_string("(X - i)(X + 2)(X - 1 - i)".to_string()).unwrap();
+	for z in p.roots_via_companion() {
+		assert!(p.eval(z).norm() < 1e-9);
+	}
+}
+
+#[test]
+fn test_root_bounds() {
+	use crate::roots::RootBound::*;
+
+	// (X - 1)(X - 2)(X - 3)(X^2 + 4), whose roots have moduli between 1 and 3
+	let p = polynomial![-6., 11., -6., 1.] * polynomial![4., 0., 1.];
+	for bound in [Cauchy, Fujiwara, Lagrange, Kojima] {
+		assert!(p.root_upper_bound(bound) >= 3.);
+		assert!(p.root_lower_bound(bound) <= 1.);
+	}
+	assert!(polynomial![-6., 11., -6., 1.].root_upper_bound(Kojima) < f64::INFINITY);
+	assert_eq!(polynomial![-2., 1.].root_upper_bound(Kojima), 2.);
+
+	assert!(polynomial![0.06, -0.5, 1.].is_schur_stable());
+	assert!(!p.is_schur_stable());
+	assert!(polynomial![6., 11., 6., 1.].is_hurwitz_stable());
+	assert!(!p.is_hurwitz_stable());
+}
+
+#[test]
+fn test_from_roots() {
+	let roots: Vec<i64> = (-6..=6).collect();
+	let expected = roots
+		.iter()
+		.fold(polynomial![1], |p, &r| p * polynomial![-r, 1]);
+	assert_eq!(Polynomial::from_roots(&roots), expected);
+	assert_eq!(Polynomial::<i64>::from_roots(&[]), polynomial![1]);
+
+	let p = Polynomial::from_roots_with_multiplicity(&[(1, 3), (0, 2), (-1, 1)]);
+	assert_eq!(
+		p,
+		polynomial![-1, 1] * polynomial![-1, 1].powi(2) * polynomial![0, 0, 1, 1]
+	);
+}
+
+#[test]
+fn test_square_free() {
+	// 3 (X - 1)^3 (X + 2)^2 (2X + 1)
+	let p =
+		polynomial![3] * polynomial![-1, 1].powi(3) * polynomial![2, 1].powi(2) * polynomial![1, 2];
+	assert_eq!(
+		p.square_free_decomposition(),
+		vec![
+			(polynomial![1, 2], 1),
+			(polynomial![2, 1], 2),
+			(polynomial![-1, 1], 3)
+		]
+	);
+	assert_eq!(
+		p.square_free_part(),
+		polynomial![-1, 1] * polynomial![2, 1] * polynomial![1, 2]
+	);
+	assert_eq!(polynomial![-1, 0, 1].square_free_decomposition().len(), 1);
+
+	let p = polynomial![-1f64, 1.].powi(3) * polynomial![2., 1.].powi(2) * polynomial![1., 2.];
+	let decomposition = p.square_free_decomposition_float();
+	assert_eq!(decomposition.len(), 3);
+	let expected = [
+		polynomial![0.5, 1.],
+		polynomial![2., 1.],
+		polynomial![-1., 1.],
+	];
+	for ((a, i), (e, j)) in decomposition.iter().zip(expected.iter().zip(1..)) {
+		assert_eq!(*i, j);
+		assert!((a - e).into_iter().all(|c| c.abs() < 1e-6));
+	}
+	let part = p.square_free_part_float();
+	let expected = polynomial![-1., 1.] * polynomial![2., 1.] * polynomial![0.5, 1.];
+	assert!((part - expected).into_iter().all(|c| c.abs() < 1e-6));
+}
+
+#[test]
+fn test_prime_field() {
+	use num_traits::{One, Zero};
+
+	use crate::fields::Fp;
+	type F = Fp<998244353>;
+
+	let a = F::new(123456789);
+	let b = F::from(-987654321i64);
+	assert_eq!(
+		(a * b).value(),
+		(123456789u128 * (998244353 * 2 - 987654321) as u128 % 998244353) as u64
+	);
+	assert_eq!(a / b * b, a);
+	assert_eq!(a - a, F::zero());
+	assert_eq!(-a + a, F::zero());
+
+	type G = Fp<7>;
+	let p: Polynomial<G> = [1, 2, 3, 4].into_iter().map(G::from).collect();
+	let inv = Polynomial::inverse_float(&p, 8);
+	assert_eq!(
+		Polynomial::short_product(&p, &inv, 8),
+		polynomial![G::one()]
+	);
+
+	// (X + 1)(X + 2) and (X + 1)(X + 3)
+	let p1: Polynomial<G> = [2, 3, 1].into_iter().map(G::from).collect();
+	let p2: Polynomial<G> = [3, 4, 1].into_iter().map(G::from).collect();
+	let (q, r) = Polynomial::euclidean_division_immutable_float(&p1, &p2);
+	assert_eq!(&p2 * &q + r, p1);
+	assert_eq!(
+		Polynomial::gcd_float_immutable(&p1, &p2),
+		polynomial![G::one(), G::one()]
+	);
+}
+
+#[test]
+fn test_prime_field_factorization() {
+	use crate::fields::Fp;
+	type F = Fp<13>;
+
+	let lin = |a: i64| polynomial![F::from(a), F::from(1)];
+	// X^2 + 2 and X^3 + 2 are irreducible over F_13
+	let quad: Polynomial<F> = [2, 0, 1].into_iter().map(F::from).collect();
+	let cubic: Polynomial<F> = [2, 0, 0, 1].into_iter().map(F::from).collect();
+	assert!(quad.is_irreducible() && cubic.is_irreducible());
+	assert!(!(&quad * &cubic).is_irreducible());
+
+	let p = lin(1) * lin(5) * lin(-3) * &quad * &cubic;
+	let ddf = p.distinct_degree_factorization();
+	assert_eq!(
+		ddf.iter()
+			.map(|(g, d)| (g.degree(), *d))
+			.collect::<Vec<_>>(),
+		vec![(3, 1), (2, 2), (3, 3)]
+	);
+	assert_eq!(ddf[0].0.equal_degree_factorization(1).len(), 3);
+	let repeated = lin(1) * lin(1);
+	assert!(std::panic::catch_unwind(|| repeated.equal_degree_factorization(1)).is_err());
+
+	let factors = p.berlekamp_factorization();
+	assert_eq!(factors.len(), 5);
+	assert_eq!(
+		factors
+			.iter()
+			.fold(polynomial![F::from(1)], |acc, g| acc * g),
+		p
+	);
+
+	let p = lin(2).powi(13) * lin(3).powi(2) * quad.powi(3) * &cubic;
+	let factors = p.factor();
+	assert_eq!(
+		factors,
+		vec![(lin(2), 13), (lin(3), 2), (quad, 3), (cubic, 1)]
+	);
+}
+
+#[test]
+fn test_integer_factorization() {
+	// -6 (X - 1)^2 (2X + 3) (X^2 + 1) (X^4 + 1)
+	let p = polynomial![-6i64]
+		* polynomial![-1, 1].powi(2)
+		* polynomial![3, 2]
+		* polynomial![1, 0, 1]
+		* polynomial![1, 0, 0, 0, 1];
+	let expected = (
+		-6,
+		vec![
+			(polynomial![-1, 1], 2),
+			(polynomial![3, 2], 1),
+			(polynomial![1, 0, 1], 1),
+			(polynomial![1, 0, 0, 0, 1], 1),
+		],
+	);
+	assert_eq!(p.factor(), Some(expected.clone()));
+	assert_eq!(p.factor_lll(), Some(expected));
+
+	// X^4 - 10X^2 + 1 is irreducible but splits modulo every prime
+	let p = polynomial![1i64, 0, -10, 0, 1];
+	assert_eq!(p.factor(), Some((1, vec![(p.clone(), 1)])));
+	assert_eq!(p.factor_lll(), Some((1, vec![(p.clone(), 1)])));
+
+	// (X^3 - 2)(X^3 + X + 5)(3X^2 - 7)
+	let p = polynomial![-2i64, 0, 0, 1] * polynomial![5, 1, 0, 1] * polynomial![-7, 0, 3];
+	let expected = vec![
+		(polynomial![-7, 0, 3], 1),
+		(polynomial![-2, 0, 0, 1], 1),
+		(polynomial![5, 1, 0, 1], 1),
+	];
+	assert_eq!(p.factor(), Some((1, expected.clone())));
+	assert_eq!(p.factor_lll(), Some((1, expected)));
+
+	// X^12 - 1 has 6 cyclotomic factors, and many more modulo small primes
+	let p = polynomial![-1i64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+	let (c, factors) = p.factor_lll().unwrap();
+	assert_eq!(factors.len(), 6);
+	assert_eq!(p.factor(), Some((c, factors)));
+
+	// The same over big integers, with a factor that does not fit in i64
+	use num::BigInt;
+	let big = |v: Vec<i64>| -> Polynomial<BigInt> { v.into_iter().map(BigInt::from).collect() };
+	let a = num_traits::pow(BigInt::from(3), 50);
+	let large = Polynomial::from(vec![-&a * 2, BigInt::from(0), BigInt::from(1)]);
+	let p = big(vec![-2, 0, 0, 1]) * &large * big(vec![5, 1, 0, 1]);
+	let expected = vec![
+		(large.clone(), 1),
+		(big(vec![-2, 0, 0, 1]), 1),
+		(big(vec![5, 1, 0, 1]), 1),
+	];
+	assert_eq!(p.factor(), Some((BigInt::from(1), expected.clone())));
+	assert_eq!(p.factor_lll(), Some((BigInt::from(1), expected)));
+	// X^2 - 3^50 = (X - 3^25)(X + 3^25)
+	let p = Polynomial::from(vec![-a, BigInt::from(0), BigInt::from(1)]);
+	let root = num_traits::pow(BigInt::from(3), 25);
+	assert_eq!(
+		p.factor().unwrap().1,
+		vec![
+			(Polynomial::from(vec![-root.clone(), BigInt::from(1)]), 1),
+			(Polynomial::from(vec![root, BigInt::from(1)]), 1)
+		]
+	);
+	assert_eq!(Polynomial::<i64>::from(vec![]).factor(), None);
+	assert_eq!(polynomial![7i32].factor(), Some((7, vec![])));
+}
+
+#[test]
+fn test_gf2_polynomial() {
+	use fields::Gf2Polynomial;
+	use num_traits::Zero;
+
+	// Pseudo-random polynomials large enough to go through Karatsuba
+	let mut state = 0x2545_f491_4f6c_dd1du64;
+	let mut random_poly = |words: usize| {
+		Gf2Polynomial::from_words(
+			(0..words)
+				.map(|_| {
+					state ^= state << 13;
+					state ^= state >> 7;
+					state ^= state << 17;
+					state
+				})
+				.collect(),
+		)
+	};
+	let a = random_poly(50);
+	let b = random_poly(37);
+	let c = random_poly(3);
+	let product = &a * &b;
+	let expected = Gf2Polynomial::from(Polynomial::<i64>::from(&a) * Polynomial::from(&b));
+	assert_eq!(product, expected);
+	assert_eq!(product.degree(), a.degree() + b.degree());
+	assert_eq!(&(&a * &c) * &b, &a * &(&b * &c));
+	assert_eq!(a.square(), &a * &a);
+
+	let (q, r) = Gf2Polynomial::euclidean_division(&product, &c);
+	assert_eq!(&(&q * &c) + &r, product);
+	assert!(r.degree() < c.degree());
+	assert_eq!(&(&product + &c) / &a, b);
+	assert_eq!(&(&product + &c) % &a, c);
+
+	assert_eq!((&a << 131) >> 131, a);
+	assert_eq!((&a << 5).degree(), a.degree() + 5);
+	assert_eq!(&a >> 5000, Gf2Polynomial::zero());
+
+	let g = random_poly(2);
+	let d = Gf2Polynomial::gcd(&(&a * &g), &(&b * &g));
+	assert!((&d % &g).is_zero());
+
+	// There are 30 irreducible polynomials of degree 8 over GF(2)
+	let count = (256..512)
+		.filter(|&n| Gf2Polynomial::from_words(vec![n]).is_irreducible())
+		.count();
+	assert_eq!(count, 30);
+	assert!(Gf2Polynomial::from_exponents(&[127, 1, 0]).is_irreducible());
+	assert!(!Gf2Polynomial::from_exponents(&[128, 1, 0]).is_irreducible());
+}
+
+#[test]
+fn test_crc() {
+	use codes::Crc;
+	use fields::Gf2Polynomial;
+	use num_traits::Zero;
+
+	// Check values of the CRC catalogue
+	let check = b"123456789";
+	let crcs = [
+		(Crc::crc32(), 0xcbf4_3926),
+		(Crc::crc32c(), 0xe306_9283),
+		(Crc::crc16_arc(), 0xbb3d),
+		(Crc::crc16_ibm_3740(), 0x29b1),
+		(Crc::crc8_smbus(), 0xf4),
+		(Crc::crc64_xz(), 0x995d_c9bb_df19_39fa),
+		(Crc::from_normal(5, 0x05, 0x1f, true, true, 0x1f), 0x19),
+		(Crc::from_normal(3, 0x3, 0, false, false, 0x7), 0x4),
+		(Crc::from_normal(12, 0x80f, 0, false, true, 0), 0xdaf),
+	];
+	let data: Vec<u8> = (0..100u32).map(|i| (i * i * 31 + 7) as u8).collect();
+	for (crc, value) in &crcs {
+		assert_eq!(crc.checksum(check), *value, "{crc:?}");
+		assert_eq!(crc.checksum_table(check), *value, "{crc:?}");
+		assert_eq!(crc.checksum_polynomial(check), *value, "{crc:?}");
+		for len in [0, 1, 7, 8, 9, 64, 100] {
+			let expected = crc.checksum_polynomial(&data[..len]);
+			assert_eq!(crc.checksum(&data[..len]), expected, "{crc:?}");
+			assert_eq!(crc.checksum_table(&data[..len]), expected, "{crc:?}");
+		}
+	}
+
+	// Without reflection nor initial value, the message followed by its CRC is a
+	// multiple of the generator
+	let crc = Crc::from_normal(16, 0x1021, 0, false, false, 0);
+	let mut message = data.clone();
+	message.extend_from_slice(&(crc.checksum(&data) as u16).to_be_bytes());
+	let bytes: Vec<i64> = message
+		.iter()
+		.rev()
+		.flat_map(|&b| (0..8).map(move |j| ((b >> j) & 1) as i64))
+		.collect();
+	assert!((&Gf2Polynomial::from(Polynomial::from(bytes)) % crc.generator()).is_zero());
+}
+
+#[test]
+fn test_reed_solomon() {
+	use codes::ReedSolomon;
+	use fields::{Gf256, Gf2m};
+	use num_traits::{Inv, One, Zero};
+
+	for x in 1..256u64 {
+		let x = Gf256::new(x);
+		assert!((x * x.inv()).is_one());
+	}
+	let alpha = Gf256::primitive_element();
+	assert_eq!(alpha, Gf256::new(2));
+	assert_eq!((1..255).filter(|&i| alpha.pow(i).is_one()).count(), 0);
+
+	let mut state = 0x9e37_79b9_7f4a_7c15u64;
+	let mut random = |bound: u64| {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state % bound
+	};
+
+	let rs = ReedSolomon::<0x11d>::new(255, 223);
+	for i in 1..=32 {
+		assert!(rs.generator().eval(alpha.pow(i)).is_zero());
+	}
+	let message: Vec<Gf256> = (0..223).map(|_| Gf256::new(random(256))).collect();
+	let codeword = rs.encode(&message);
+	assert_eq!(rs.decode(&codeword, &[]), Ok(message.clone()));
+	// 2e + f <= 32
+	for (errors, erasures) in [(16, 0), (0, 32), (10, 12), (1, 30)] {
+		let mut positions: Vec<usize> = Vec::new();
+		while positions.len() < errors + erasures {
+			let j = random(255) as usize;
+			if !positions.contains(&j) {
+				positions.push(j);
+			}
+		}
+		let mut received = codeword.clone();
+		for &j in &positions {
+			received[j] = received[j] + Gf256::new(1 + random(255));
+		}
+		assert_eq!(
+			rs.decode(&received, &positions[errors..]),
+			Ok(message.clone())
+		);
+	}
+	let mut received = codeword.clone();
+	received[0] = Gf256::zero();
+	assert!(rs.decode(&received, &(1..34).collect::<Vec<_>>()).is_err());
+
+	// A small code over GF(16) whose generator has the roots 1, α, ..., α^5
+	type F = Gf2m<0x13>;
+	let rs = ReedSolomon::<0x13>::with_first_root(15, 9, 0);
+	let message: Vec<F> = (0..9).map(F::new).collect();
+	let mut received = rs.encode(&message);
+	received[3] = received[3] + F::new(7);
+	received[14] = received[14] + F::new(1);
+	received[8] = F::zero();
+	assert_eq!(rs.decode(&received, &[8]), Ok(message));
+}
+
+#[test]
+fn test_berlekamp_massey() {
+	use fields::Fp;
+
+	// s_n = 2 s_(n-1) - s_(n-3) + 0.5 s_(n-4)
+	let mut s = vec![1f64, -2., 0.5, 3.];
+	for n in 4..12 {
+		s.push(2. * s[n - 1] - s[n - 3] + 0.5 * s[n - 4]);
+	}
+	let c = Polynomial::berlekamp_massey(&s);
+	let expected = polynomial![1., -2., 0., 1., -0.5];
+	assert!((c - expected).into_iter().all(|x| x.abs() < 1e-9));
+
+	// Too few terms to find the recurrence of length 4
+	assert!(Polynomial::berlekamp_massey(&s[..5]).degree() < 4);
+	assert_eq!(Polynomial::<f64>::berlekamp_massey(&[]), polynomial![1.]);
+
+	// A random recurrence of length 5 over a prime field
+	type F = Fp<1_000_000_007>;
+	let c: Vec<F> = [1, 17, 0, 123_456, 5, 999]
+		.into_iter()
+		.map(F::from)
+		.collect();
+	let mut s: Vec<F> = [3, 1, 4, 1, 5].into_iter().map(F::from).collect();
+	for n in 5..20 {
+		let next = (1..6).fold(F::from(0), |acc, j| acc - c[j] * s[n - j]);
+		s.push(next);
+	}
+	assert_eq!(Polynomial::berlekamp_massey(&s), Polynomial::from(c));
+}
+
+#[test]
+fn test_powmod_and_recurrence() {
+	use fields::Fp;
+
+	let p = polynomial![3i64, -1, 2];
+	let m = polynomial![1i64, 4, 0, -1];
+	for exp in [0u32, 1, 2, 5, 8] {
+		let (_, expected) = Polynomial::euclidean_division_immutable(&p.powi(exp), &m);
+		assert_eq!(p.powmod(exp, &m), expected);
+	}
+	let p = polynomial![0.5, -1., 0.25];
+	let m = polynomial![1., 4., 0., 2.];
+	let (_, expected) = Polynomial::euclidean_division_immutable_float(&p.powi(7), &m);
+	assert!((p.powmod_float(7, &m) - expected)
+		.into_iter()
+		.all(|c: f64| c.abs() < 1e-9));
+
+	// Tribonacci numbers
+	let charpoly = polynomial![-1i64, -1, -1, 1];
+	let mut t = vec![0i64, 0, 1];
+	for n in 3..60 {
+		t.push(t[n - 1] + t[n - 2] + t[n - 3]);
+	}
+	for n in 0..60usize {
+		assert_eq!(charpoly.recurrence_term(&t[..3], n), t[n]);
+	}
+	assert_eq!(
+		polynomial![-0.5, 0., 1.].recurrence_term_float(&[1., 1.], 21),
+		1. / 1024.
+	);
+
+	// The characteristic polynomial is the reverse of the connection polynomial
+	type F = Fp<998_244_353>;
+	let s: Vec<F> = t.iter().map(|&x| F::from(x)).collect();
+	let charpoly = Polynomial::berlekamp_massey(&s[..10]).rev();
+	assert_eq!(charpoly.degree(), 3);
+	let n = 123_456_789_012u64;
+	let expected = charpoly.recurrence_term_float(&s[..3], n);
+	assert_eq!(charpoly.recurrence_term_float(&s[1..4], n - 1), expected);
+	assert_eq!(charpoly.recurrence_term_float(&s[..3], 59), s[59]);
+}
+
+#[test]
+fn test_big_coefficients() {
+	use num::{BigInt, BigRational};
+	use num_traits::One;
+
+	let big = |x: i64| BigInt::from(x);
+	// (10^18 X + 7)^3 overflows i64 and i128
+	let p = polynomial![big(7), big(1_000_000_000_000_000_000)];
+	let cube = p.powi(3);
+	assert_eq!(cube[3], big(10).pow(54));
+	assert_eq!(cube[1], big(147) * big(10).pow(18));
+	assert_eq!(Polynomial::exact_division(&cube, &p), p.powi(2));
+	let q = polynomial![big(-3), big(0), big(1)];
+	assert_eq!(
+		Polynomial::gcd_prs(&(&cube * &q), &(&p * &q.powi(2))),
+		&p * &q
+	);
+	assert_eq!(
+		(&cube * &q).square_free_decomposition(),
+		vec![(q.clone(), 1), (p.clone(), 3)]
+	);
+	let (quotient, remainder) = Polynomial::euclidean_division_immutable(&(&cube + 5), &q);
+	assert_eq!(&quotient * &q + remainder, &cube + 5);
+
+	// Binomial coefficients beyond u64
+	let b = Polynomial::<BigInt>::bernstein(70u32, 35u32);
+	assert_eq!(b[35], "112186277816662845432".parse::<BigInt>().unwrap());
+
+	let parsed = Polynomial::<BigInt>::parse_string("(X + 2)^3 - 4X".to_string()).unwrap();
+	assert_eq!(parsed, polynomial![big(8), big(8), big(6), big(1)]);
+	assert_eq!(
+		parsed.to_string(),
+		"Polynomial(3)\n 1 X^3\n+6 X^2\n+8 X^1\n+8"
+	);
+	assert_eq!(
+		cube.to_string(),
+		format!(
+			"Polynomial(3)\n {} X^3\n+{} X^2\n+{} X^1\n+343",
+			big(10).pow(54),
+			big(21) * big(10).pow(36),
+			big(147) * big(10).pow(18)
+		)
+	);
+	assert_eq!(cube.canonical().to_string().parse(), Ok(cube.clone()));
+
+	// Rationals are exact, even below TOL
+	let r = |n: i64, d: i64| BigRational::new(big(n), big(d));
+	let tiny = r(1, 1_000_000_000_000);
+	let p = polynomial![r(1, 3), tiny.clone()];
+	assert_eq!(p.degree(), 1);
+	assert_eq!(p.to_string(), "Polynomial(1)\n 1/1000000000000 X^1\n+1/3");
+	assert_eq!(p.canonical().to_string(), "1/1000000000000X + 1/3");
+	assert_eq!(p.canonical().to_string().parse(), Ok(p.clone()));
+	let a = &p * &polynomial![r(-1, 2), r(1, 1)];
+	let b = &p * &polynomial![r(2, 7), r(1, 1)];
+	let g = Polynomial::gcd_float_immutable(&a, &b);
+	assert_eq!(g, polynomial![r(1, 3) / tiny.clone(), BigRational::one()]);
+	let (q, rem) = Polynomial::euclidean_division_immutable_float(&a, &p);
+	assert_eq!(q, polynomial![r(-1, 2), r(1, 1)]);
+	assert!(rem.is_empty());
+}
+
+#[test]
+fn test_exact_literals() {
+	use num::{BigInt, BigRational};
+
+	// Decimals are exact rationals, not the closest f64
+	let p = Polynomial::<BigRational>::parse_string("0.1X - 2.25".to_string()).unwrap();
+	let r = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+	assert_eq!(p, polynomial![r(-9, 4), r(1, 10)]);
+	let p = Polynomial::<BigRational>::parse_string("X/3 + 1".to_string()).unwrap();
+	assert_eq!(p, polynomial![r(1, 1), r(1, 3)]);
+
+	// Integers beyond 2^53 and u64
+	let p = Polynomial::<i64>::parse_string("-9223372036854775808 + 9007199254740993X".to_string())
+		.unwrap();
+	assert_eq!(p, polynomial![i64::MIN, 9007199254740993]);
+	let p =
+		Polynomial::<BigInt>::parse_string("123456789012345678901234567890X".to_string()).unwrap();
+	assert_eq!(
+		p[1],
+		"123456789012345678901234567890".parse::<BigInt>().unwrap()
+	);
+	let p = Polynomial::<BigInt>::parse_string("10^30".to_string()).unwrap();
+	assert_eq!(p[0], BigInt::from(10).pow(30));
+
+	// Floats are correctly rounded, complex parts as well
+	let p = Polynomial::<f64>::parse_string("0.3X".to_string()).unwrap();
+	assert_eq!(p[1], 0.3);
+	let p = Polynomial::<Complex<f64>>::parse_string("0.1 + 0.7i".to_string()).unwrap();
+	assert_eq!(p[0], Complex::new(0.1, 0.7));
+
+	// Overflow and inexact literals are errors
+	assert_eq!(
+		Polynomial::<i32>::parse_string("3000000000X".to_string()),
+		Err("LiteralOverflow(3000000000)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<u8>::parse_string("-1".to_string()),
+		Err("LiteralOverflow(-1)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("2.5X".to_string()),
+		Err("InexactLiteral(2.5)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("2.50X".to_string()),
+		Err("InexactLiteral(2.50)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("2.0X".to_string()).unwrap(),
+		polynomial![0, 2]
+	);
+}
+
+#[test]
+fn test_literal_forms() {
+	use fields::Gf256;
+	use num::{BigInt, BigRational};
+
+	// Scientific notation, exact for rationals and integers
+	let p = Polynomial::<BigRational>::parse_string("1.5e-3X".to_string()).unwrap();
+	assert_eq!(p[1], BigRational::new(BigInt::from(3), BigInt::from(2000)));
+	let p = Polynomial::<f64>::parse_string("6.02E23 + 1e+2X".to_string()).unwrap();
+	assert_eq!(p, polynomial![6.02e23, 100.]);
+	let p = Polynomial::<i64>::parse_string("2.5e3X^2 - 1E0".to_string()).unwrap();
+	assert_eq!(p, polynomial![-1, 0, 2500]);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("25e-1".to_string()),
+		Err("InexactLiteral(25e-1)".to_string())
+	);
+
+	// Underscores, hexadecimal and binary literals
+	let p = Polynomial::<u32>::parse_string("1_000_000X + 0x1F".to_string()).unwrap();
+	assert_eq!(p, polynomial![31, 1_000_000]);
+	let p = Polynomial::<i32>::parse_string("0b101X^0b10 - 0xff".to_string()).unwrap();
+	assert_eq!(p, polynomial![-255, 0, 5]);
+	let p = Polynomial::<Gf256>::parse_string("0x53X + 0b1".to_string()).unwrap();
+	assert_eq!(p, polynomial![Gf256::new(1), Gf256::new(0x53)]);
+	// Without digits, `0x` is still a product
+	let p = Polynomial::<i32>::parse_string("0x + 2e".to_string());
+	assert_eq!(p, Err("UnboundNames(e)".to_string()));
+
+	// Overflows are checked
+	assert_eq!(
+		Polynomial::<i32>::parse_string("0x1_0000_0000".to_string()),
+		Err("LiteralOverflow(0x100000000)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<f64>::parse_string("1e400X".to_string()),
+		Err("LiteralOverflow(1e400)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<i64>::parse_string("1e30".to_string()),
+		Err("LiteralOverflow(1e30)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<f64>::parse_string("1e99999999999999999999".to_string()),
+		Err("LiteralOverflow(1e99999999999999999999)".to_string())
+	);
+
+	// Malformed literals
+	for (s, literal) in [
+		("1.2.3", "1.2.3"),
+		("1e5.3 + X", "1e5.3"),
+		("1__000", "1__000"),
+		("1_ + X", "1_"),
+		("0b102", "0b102"),
+		("0x1F.8", "0x1F.8"),
+		("2e+X", "2e+X"),
+		(". + X", "."),
+	] {
+		assert_eq!(
+			Polynomial::<f64>::parse_string(s.to_string()),
+			Err(format!("MalformedLiteral({literal})"))
+		);
+	}
+	let error = expr::parse_expr("X + 1_x").unwrap_err();
+	assert_eq!(error.to_string(), "MalformedLiteral(1_x) at 4..7");
+
+	// The kinds of errors can be matched on
+	use errors::PolynomialError;
+	let kind = |s: &str| s.parse::<Polynomial<i32>>().unwrap_err().kind().clone();
+	assert_eq!(
+		kind("X + 0x100000000"),
+		PolynomialError::LiteralOverflow("0x100000000".to_string())
+	);
+	assert_eq!(
+		kind("2.5"),
+		PolynomialError::InexactLiteral("2.5".to_string())
+	);
+	assert!(matches!(
+		kind("1__000"),
+		PolynomialError::MalformedLiteral(literal) if literal == "1__000"
+	));
+}
+
+#[test]
+fn test_parser_coefficient_types() {
+	use fields::{Fp, Gf256};
+	use num::{BigInt, BigRational};
+
+	// The same grammar for every coefficient type
+	let s = "(2X - 1)^2 - 3(X + 1)X";
+	let expected = polynomial![1, -7, 1];
+	assert_eq!(
+		Polynomial::<i64>::parse_string(s.to_string()).unwrap(),
+		expected
+	);
+	assert_eq!(
+		Polynomial::<f64>::parse_string(s.to_string()).unwrap(),
+		polynomial![1., -7., 1.]
+	);
+	type F = Fp<7>;
+	assert_eq!(
+		Polynomial::<F>::parse_string(s.to_string()).unwrap(),
+		polynomial![F::from(1), F::from(0), F::from(1)]
+	);
+	let p = Polynomial::<Complex<BigRational>>::parse_string("X/2 + 0.5i".to_string()).unwrap();
+	let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+	assert_eq!(
+		p,
+		polynomial![
+			Complex::new(BigRational::from_integer(BigInt::from(0)), half.clone()),
+			Complex::new(half, BigRational::from_integer(BigInt::from(0)))
+		]
+	);
+
+	// Exponents are integers, not field elements
+	assert_eq!(
+		Polynomial::<Fp<3>>::parse_string("X^(2 + 2)".to_string()).unwrap(),
+		Polynomial::<Fp<3>>::parse_string("X⁴".to_string()).unwrap()
+	);
+	let p = Polynomial::<Gf256>::parse_string("(X + 1)^2".to_string()).unwrap();
+	assert_eq!(p, polynomial![Gf256::new(1), Gf256::new(0), Gf256::new(1)]);
+
+	// Precedence
+	let parse = |s: &str| Polynomial::<i64>::parse_string(s.to_string());
+	assert_eq!(parse("-2^2"), Ok(polynomial![-4]));
+	assert_eq!(parse("2^3^2"), Ok(polynomial![512]));
+	assert_eq!(parse("2^3X"), Ok(polynomial![0, 8]));
+	assert_eq!(parse("-X^2 + 2 3X"), Ok(polynomial![0, 6, -1]));
+	assert_eq!(parse("X¹⁰"), parse("X^10"));
+	assert_eq!(
+		Polynomial::<f64>::parse_string("2^-1 X".to_string()),
+		Ok(polynomial![0., 0.5])
+	);
+
+	// Errors
+	assert_eq!(parse(""), Err("EmptyStringInput".to_string()));
+	assert_eq!(parse("(X + 1"), Err("ImpossibleOpen".to_string()));
+	assert_eq!(parse("X + 1)"), Err("ImpossibleClose".to_string()));
+	assert_eq!(
+		parse("X +"),
+		Err("BinaryOperatorOneOperand('+')".to_string())
+	);
+	assert_eq!(
+		parse("* X"),
+		Err("BinaryOperatorZeroOperand('*')".to_string())
+	);
+	assert_eq!(parse("-* X"), Err("UnaryMinusFailed('*')".to_string()));
+	assert_eq!(parse("X ? 1"), Err("UnsupportedCharacter(?)".to_string()));
+	assert_eq!(parse("2i"), Err("UnsupportedCharacter(i)".to_string()));
+	assert!(parse("X^X")
+		.unwrap_err()
+		.starts_with("ImpossiblePower2Polynomials"));
+	assert!(parse("1 / X")
+		.unwrap_err()
+		.starts_with("ImpossibleDivision"));
+	assert!(parse("X^-1").unwrap_err().starts_with("ImpossiblePower"));
+	// Inverses of constants are exact divisions, and their powers are checked
+	assert_eq!(parse("1^-3 + (-1)^-1"), Ok(polynomial![0]));
+	assert_eq!(
+		parse("2^-1"),
+		Err("ImpossibleDivision(Polynomial([1]), Polynomial([2]))".to_string())
+	);
+	assert_eq!(parse("2^62"), Ok(polynomial![1 << 62]));
+	assert_eq!(
+		parse("2^63"),
+		Err("PowerOverflow(Polynomial([2]), 63)".to_string())
+	);
+	assert_eq!(parse("(-2)^63"), Ok(polynomial![i64::MIN]));
+	assert_eq!(
+		Polynomial::<f64>::parse_string("10^400".to_string()),
+		Err("PowerOverflow(Polynomial([10.0]), 400)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<f64>::parse_string("10^-2".to_string()),
+		Ok(polynomial![0.01])
+	);
+	// Powers of polynomials are limited in degree
+	assert_eq!(parse("(X^2)^5000").map(|p| p.degree()), Ok(10_000));
+	assert_eq!(
+		parse("(X^2)^5001"),
+		Err("PowerOverflow(Polynomial([0, 0, 1]), 5001)".to_string())
+	);
+	assert_eq!(
+		parse("X^100000000000000"),
+		Err("PowerOverflow(Polynomial([0, 1]), 100000000000000)".to_string())
+	);
+	assert_eq!(parse("0^100000000000000"), Ok(polynomial![0]));
+	// So is the arithmetic of the polynomials
+	let parse = |s: &str| Polynomial::<i32>::parse_string(s.to_string());
+	assert_eq!(
+		parse("(X + 1)^40"),
+		Err("PowerOverflow(Polynomial([1, 1]), 40)".to_string())
+	);
+	assert_eq!(parse("(X + 1)^30").map(|p| p[15]), Ok(155117520));
+	assert_eq!(
+		parse("2147483647 + X + 1"),
+		Err("OperationOverflow('+', Polynomial([2147483647, 1]), Polynomial([1]))".to_string())
+	);
+	assert_eq!(
+		parse("65536X * 65536"),
+		Err("OperationOverflow('*', Polynomial([0, 65536]), Polynomial([65536]))".to_string())
+	);
+	assert_eq!(
+		parse("(65536X + 1)(X + 65536)"),
+		Err("OperationOverflow('*', Polynomial([1, 65536]), Polynomial([65536, 1]))".to_string())
+	);
+	assert_eq!(
+		parse("-(-2147483647 - 1)"),
+		Err("NegationOverflow(Polynomial([-2147483648]))".to_string())
+	);
+	assert_eq!(
+		Polynomial::<u32>::parse_string("X - 1".to_string()),
+		Err("OperationOverflow('-', Polynomial([0, 1]), Polynomial([1]))".to_string())
+	);
+	assert_eq!(
+		Polynomial::<u32>::parse_string("-X".to_string()),
+		Err("NegationOverflow(Polynomial([0, 1]))".to_string())
+	);
+	assert_eq!(
+		parse("D(100000000 X^100)"),
+		Err("FunctionFailed(D, the coefficients overflow)".to_string())
+	);
+	assert_eq!(
+		parse("compose(X^2 + 1, 65536X)"),
+		Err("FunctionFailed(compose, the coefficients overflow)".to_string())
+	);
+	assert_eq!(
+		parse("compose(X^10000, X^2)"),
+		Err("FunctionFailed(compose, the degree 20000 exceeds 10000)".to_string())
+	);
+	assert!(
+		Polynomial::<Complex<i32>>::parse_string("65536i X * 65536i".to_string())
+			.unwrap_err()
+			.starts_with("OperationOverflow")
+	);
+	assert_eq!(
+		Polynomial::<Complex<i32>>::parse_string("(1 + 2i)X * (3 - i)".to_string()),
+		Ok(polynomial![Complex::new(0, 0), Complex::new(5, 5)])
+	);
+	assert_eq!(
+		Polynomial::<f64>::parse_string("1e300X * 1e300".to_string()),
+		Err("OperationOverflow('*', Polynomial([0.0, 1e300]), Polynomial([1e300]))".to_string())
+	);
+}
+
+#[test]
+fn test_deep_nesting() {
+	use num::{BigInt, BigRational};
+
+	// Up to 128 nested parentheses, calls, minus signs and exponents
+	let nested = |open: &str, inner: &str, close: &str, n: usize| {
+		format!("{}{inner}{}", open.repeat(n), close.repeat(n))
+	};
+	let parse = |s: &str| Polynomial::<i64>::parse_string(s.to_string());
+	assert_eq!(
+		parse(&nested("(", "X + 1", ")", 127)),
+		Ok(polynomial![1, 1])
+	);
+	assert_eq!(parse(&nested("-", "X", "", 127)), Ok(polynomial![0, -1]));
+	assert_eq!(
+		parse(&format!("X^{}", nested("1^", "1", "", 126))),
+		Ok(polynomial![0, 1])
+	);
+	assert_eq!(parse(&nested("-(", "X", ")", 63)), Ok(polynomial![0, -1]));
+	let environment = expr::Environment::new().function("f", 1, |p| Ok(p[0].clone()));
+	assert_eq!(
+		Polynomial::<i64>::parse_string_in(&nested("f(", "X", ")", 127), &environment),
+		Ok(polynomial![0, 1])
+	);
+	assert_eq!(
+		Polynomial::<Complex<BigRational>>::parse_string(nested("(", "X/3", ")", 127)),
+		Ok(polynomial![
+			Complex::from(BigRational::from_integer(BigInt::from(0))),
+			Complex::from(BigRational::new(BigInt::from(1), BigInt::from(3)))
+		])
+	);
+	// Deeper nestings are errors instead of stack overflows
+	for s in [
+		nested("(", "X", ")", 128),
+		nested("(", "X", ")", 200_000),
+		nested("-", "X", "", 200_000),
+		nested("2^", "X", "", 200_000),
+		nested("D(", "X", ")", 200_000),
+		nested("(", "", "", 200_000),
+	] {
+		assert_eq!(parse(&s), Err("NestingTooDeep".to_string()));
+	}
+	let error = expr::parse_expr(&nested("(", "X", ")", 300)).unwrap_err();
+	assert_eq!(
+		error.span(),
+		expr::Span {
+			start: 128,
+			end: 129
+		}
+	);
+
+	// Long chains of operators are not nested
+	let sum = format!("{}1", "X + ".repeat(200_000));
+	assert_eq!(parse(&sum), Ok(polynomial![1, 200_000]));
+	let expr = expr::parse_expr(&sum).unwrap();
+	assert_eq!(expr.to_string(), sum);
+	assert!(expr.names().is_empty());
+	assert_eq!(
+		parse(&format!("X^(2{})", " - 1 + 1".repeat(100_000))),
+		Ok(polynomial![0, 0, 1])
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string(format!("X - 2147483648{}", " * 1".repeat(200_000))),
+		Ok(polynomial![i32::MIN, 1])
+	);
+	assert_eq!(
+		parse(&format!("(X + 1) * {}", "(X - 1) % ".repeat(100_000) + "X")),
+		Ok(polynomial![0])
+	);
+}
+
+#[test]
+fn test_expr() {
+	use expr::{parse_expr, BinaryOp, Expr, ExprKind, Span};
+	use fields::Fp;
+
+	let span = |start, end| Span { start, end };
+	let expr = parse_expr("-(X + 1)²").unwrap();
+	assert_eq!(expr.span, span(0, 10));
+	let ExprKind::Neg(power) = &expr.kind else {
+		panic!("{expr:?}")
+	};
+	let ExprKind::Binary(BinaryOp::Pow, base, exponent) = &power.kind else {
+		panic!("{power:?}")
+	};
+	assert_eq!(base.span, span(1, 8));
+	assert!(matches!(base.kind, ExprKind::Group(_)));
+	assert_eq!(exponent.span, span(8, 10));
+	assert_eq!(expr.eval::<i32>(), Ok(polynomial![-1, -2, -1]));
+	assert_eq!(
+		expr.eval::<Fp<3>>().unwrap(),
+		polynomial![Fp::<3>::from(2), Fp::from(1), Fp::from(2)]
+	);
+
+	// Printing parses back to the same tree
+	for s in [
+		"2X(X + 1) - 3",
+		"-X^2 * -X",
+		"X^-1 + 2^3^2",
+		"(1 - X) - (2 - X)",
+		"1.5i / (2 + i)",
+		"X^(2 - 1)",
+	] {
+		let expr = parse_expr(s).unwrap();
+		let printed = parse_expr(&expr.to_string()).unwrap();
+		assert_eq!(printed.to_string(), expr.to_string());
+		assert_eq!(printed.eval::<Complex<f64>>(), expr.eval::<Complex<f64>>());
+	}
+	// Parentheses are added to trees built by hand
+	let x = || {
+		Box::new(Expr {
+			kind: ExprKind::Variable("X".to_string()),
+			span: Span::default(),
+		})
+	};
+	let sum = Expr {
+		kind: ExprKind::Binary(BinaryOp::Add, x(), x()),
+		span: Span::default(),
+	};
+	let product = Expr {
+		kind: ExprKind::Binary(BinaryOp::Mul, Box::new(sum.clone()), Box::new(sum)),
+		span: Span::default(),
+	};
+	assert_eq!(product.to_string(), "(X + X) * (X + X)");
+	assert_eq!(product.eval::<i32>(), Ok(polynomial![0, 0, 4]));
+
+	// Errors are located in the source
+	let error = parse_expr("X + (2 *)").unwrap_err();
+	assert_eq!(error.span(), span(8, 9));
+	assert_eq!(error.to_string(), "BinaryOperatorOneOperand('*') at 8..9");
+	assert_eq!(parse_expr("(X + 1").unwrap_err().span(), span(0, 1));
+	assert_eq!(parse_expr("X -").unwrap_err().span(), span(3, 3));
+	let error = parse_expr("1 + X / (X - 1)")
+		.unwrap()
+		.eval::<f64>()
+		.unwrap_err();
+	assert_eq!(error.span(), span(4, 15));
+	let error = parse_expr("X + 2.5").unwrap().eval::<i32>().unwrap_err();
+	assert_eq!(error.to_string(), "InexactLiteral(2.5) at 4..7");
+}
+
+#[test]
+fn test_parser_options() {
+	use expr::{parse_expr_with, ParserOptions, Span};
+
+	let options = ParserOptions::new().variable("t");
+	let parse = |s: &str, options: &ParserOptions| Polynomial::<i32>::parse_string_with(s, options);
+	assert_eq!(parse("t^2 - 3T + 2", &options), Ok(polynomial![2, -3, 1]));
+	assert_eq!(parse("X + 1", &options), Err("UnboundNames(X)".to_string()));
+	// Default options are case insensitive, as before
+	assert_eq!(
+		parse("x^2 + X", &ParserOptions::default()),
+		Ok(polynomial![0, 1, 1])
+	);
+	let options = ParserOptions::new().variable("z").case_sensitive(true);
+	assert_eq!(parse("Z", &options), Err("UnboundNames(Z)".to_string()));
+
+	// Multi-letter names, juxtaposed with imaginary units
+	let options = ParserOptions::new().variable("omega");
+	let p = Polynomial::<Complex<f64>>::parse_string_with("2iomega - omegai", &options).unwrap();
+	assert_eq!(p, polynomial![Complex::new(0., 0.), Complex::new(0., 1.)]);
+	let expr = parse_expr_with("3 omega^2", &options).unwrap();
+	assert_eq!(expr.to_string(), "3 * omega^2");
+	assert_eq!(
+		parse_expr_with("omegas", &options)
+			.unwrap()
+			.eval::<f64>()
+			.unwrap_err()
+			.span(),
+		Span { start: 0, end: 6 }
+	);
+
+	// Explicit multiplications only
+	let options = ParserOptions::new().implicit_multiplication(false);
+	assert_eq!(parse("2 * X * (X + 1)", &options), Ok(polynomial![0, 2, 2]));
+	assert_eq!(parse("2X", &options), Err("MissingOperator".to_string()));
+	assert_eq!(
+		parse_expr_with("X (X + 1)", &options).unwrap_err().span(),
+		Span { start: 2, end: 3 }
+	);
+
+	// Printing with the same name
+	let p = polynomial![1, 0, 2];
+	assert!(p.with_variable("s").to_latex().contains("s^{2}"));
+	assert_eq!(p.with_variable("X").to_string(), p.to_string());
+}
+
+#[test]
+fn test_environment() {
+	use expr::{parse_expr, Environment, ExprKind};
+
+	// Constants bound from a map
+	let environment: Environment<i32> = [("a", 2), ("b", -1), ("c", 3)].into_iter().collect();
+	assert_eq!(
+		Polynomial::parse_string_in("a*X^2 + b*X + c", &environment),
+		Ok(polynomial![3, -1, 2])
+	);
+	assert_eq!(
+		Polynomial::parse_string_in("a X (X + c) - b", &environment),
+		Ok(polynomial![1, 6, 2])
+	);
+	// A constant applied to one argument is a product
+	assert_eq!(
+		Polynomial::parse_string_in("a(X + 1)", &environment),
+		Ok(polynomial![2, 2])
+	);
+
+	// Unbound parameters are kept in the expression and listed together
+	let expr = parse_expr("p X^2 + q_r + p").unwrap();
+	assert_eq!(expr.names(), ["p", "q_r"]);
+	let error = expr.eval_in(&environment).unwrap_err();
+	assert_eq!(error.to_string(), "UnboundNames(p, q_r) at 0..1");
+	assert_eq!(
+		error.kind(),
+		&errors::PolynomialError::UnboundNames(vec!["p".to_string(), "q_r".to_string()])
+	);
+	let environment = environment
+		.constant("p", 5)
+		.polynomial("q_r", polynomial![0, 0, 0, 1]);
+	assert_eq!(expr.eval_in(&environment), Ok(polynomial![5, 0, 5, 1]));
+
+	// Functions, whose arguments are separated by commas even after digits
+	let environment = Environment::<f64>::new()
+		.function("sum", 2, |args| Ok(&args[0] + &args[1]))
+		.function("inverse", 1, |args| {
+			if args[0].degree() > 0 || num_traits::Zero::is_zero(&args[0]) {
+				return Err("not an invertible constant".to_string());
+			}
+			Ok(polynomial![1. / args[0][0]])
+		});
+	let expr = parse_expr("sum(1,5, X)").unwrap();
+	assert!(
+		matches!(&expr.kind, ExprKind::Call(name, arguments) if name == "sum" && arguments.len() == 3)
+	);
+	assert_eq!(expr.to_string(), "sum(1, 5, X)");
+	assert_eq!(
+		Polynomial::parse_string_in("sum(X^2,2) inverse(4)", &environment),
+		Ok(polynomial![0.5, 0., 0.25])
+	);
+	// Decimal commas are still read outside calls
+	assert_eq!(
+		Polynomial::parse_string_in("inverse((0,5))", &environment),
+		Ok(polynomial![2.])
+	);
+	assert_eq!(
+		Polynomial::parse_string_in("sum(X)", &environment),
+		Err("WrongArity(sum, 2, 1)".to_string())
+	);
+	assert_eq!(
+		Polynomial::parse_string_in("1 + inverse(X)", &environment),
+		Err("FunctionFailed(inverse, not an invertible constant)".to_string())
+	);
+	assert_eq!(
+		Polynomial::parse_string_in("sum(1, 2", &environment),
+		Err("ImpossibleOpen".to_string())
+	);
+	// With whitespace, a name and a parenthesis are multiplied
+	assert_eq!(
+		Polynomial::parse_string_in("sum (X)", &environment),
+		Err("UnboundNames(sum)".to_string())
+	);
+}
+
+#[test]
+fn test_parser_division() {
+	use fields::{Fp, Gf256};
+	use num::{BigInt, BigRational};
+
+	// Exact divisions by polynomials
+	assert_eq!(
+		Polynomial::<i32>::parse_string("(X^2 - 1)/(X - 1)".to_string()),
+		Ok(polynomial![1, 1])
+	);
+	assert_eq!(
+		Polynomial::<i64>::parse_string("(6X^2 + 7X + 2) / (2X + 1)".to_string()),
+		Ok(polynomial![2, 3])
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("4X / 2".to_string()),
+		Ok(polynomial![0, 2])
+	);
+	assert_eq!(
+		Polynomial::<f64>::parse_string("(X^3 - 8) / (2X - 4)".to_string()),
+		Ok(polynomial![2., 1., 0.5])
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("(X^2 + 1)/(X - 1)".to_string()),
+		Err("ImpossibleDivision(Polynomial([1, 0, 1]), Polynomial([-1, 1]))".to_string())
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("X / 2".to_string()),
+		Err("ImpossibleDivision(Polynomial([0, 1]), Polynomial([2]))".to_string())
+	);
+	assert_eq!(
+		Polynomial::<f64>::parse_string("X / (X - X)".to_string()),
+		Err("ImpossibleDivision(Polynomial([0.0, 1.0]), Polynomial([]))".to_string())
+	);
+
+	// Remainders, with `%` or `mod`
+	assert_eq!(
+		Polynomial::<i32>::parse_string("(X^3 + 2X + 5) % (X^2 + 1)".to_string()),
+		Ok(polynomial![5, 1])
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("X^5 mod (X^2 - X - 1) + 1".to_string()),
+		Ok(polynomial![4, 5])
+	);
+	assert_eq!(
+		Polynomial::<i64>::parse_string("10 mod 3".to_string()),
+		Ok(polynomial![1])
+	);
+	assert_eq!(
+		Polynomial::<i64>::parse_string("7 % 3".to_string()),
+		Ok(polynomial![1])
+	);
+	assert_eq!(
+		Polynomial::<i64>::parse_string("-7 % 3".to_string()),
+		Ok(polynomial![2])
+	);
+	assert_eq!(
+		Polynomial::<i64>::parse_string("10 / 5".to_string()),
+		Ok(polynomial![2])
+	);
+	// Pseudo-remainders for the other divisors over the integers
+	assert_eq!(
+		Polynomial::<i64>::parse_string("X mod 2".to_string()),
+		Ok(polynomial![])
+	);
+	assert_eq!(
+		Polynomial::<i64>::parse_string("(X^2 + 1) % (2X + 1)".to_string()),
+		Ok(polynomial![5])
+	);
+	assert!(Polynomial::<i64>::parse_string("X % 0".to_string())
+		.unwrap_err()
+		.starts_with("ImpossibleDivision"));
+	let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+	assert_eq!(
+		Polynomial::<BigRational>::parse_string("(X^2 + 1) % (2X)".to_string()),
+		Ok(polynomial![BigRational::from(BigInt::from(1))])
+	);
+	assert!(
+		Polynomial::<BigRational>::parse_string("(X^2 + 1) / (2X) - X/2".to_string())
+			.unwrap_err()
+			.starts_with("ImpossibleDivision")
+	);
+	assert_eq!(
+		Polynomial::<BigRational>::parse_string("X^2 / (2X)".to_string()),
+		Ok(polynomial![BigRational::from(BigInt::from(0)), half])
+	);
+	let expr = expr::parse_expr("X^2 mod (X+1) * 3").unwrap();
+	assert_eq!(expr.to_string(), "X^2 % (X + 1) * 3");
+	// In exponents, remainders are computed on integers
+	assert_eq!(
+		Polynomial::<Fp<5>>::parse_string("X^(7 % 4)".to_string()),
+		Polynomial::<Fp<5>>::parse_string("X^3".to_string())
+	);
+
+	// gcd is built in
+	assert_eq!(
+		Polynomial::<i32>::parse_string("gcd(X^2 - 1, X^2 + 2X + 1)".to_string()),
+		Ok(polynomial![1, 1])
+	);
+	assert_eq!(
+		Polynomial::<f64>::parse_string("gcd((X - 2)(X + 3), 2X^2 - 8)".to_string()),
+		Ok(polynomial![-2., 1.])
+	);
+	assert_eq!(
+		Polynomial::<Gf256>::parse_string("gcd(X^2 + 1, X + 1)".to_string()),
+		Ok(polynomial![Gf256::new(1), Gf256::new(1)])
+	);
+	assert_eq!(
+		Polynomial::<i32>::parse_string("gcd(X, 2X + 1)".to_string()),
+		Ok(polynomial![1])
+	);
+	assert_eq!(
+		Polynomial::<i64>::parse_string("gcd(2X^2 + 2X, 4X + 4)".to_string()),
+		Ok(polynomial![2, 2])
+	);
+	// A binding replaces the built-in function
+	let environment = expr::Environment::<i32>::new().function("gcd", 2, |_| Ok(polynomial![42]));
+	assert_eq!(
+		Polynomial::parse_string_in("gcd(X, 2)", &environment),
+		Ok(polynomial![42])
+	);
+}
+
+#[test]
+fn test_builtin_functions() {
+	use fields::Fp;
+	use num::{BigInt, BigRational};
+
+	let parse = |s: &str| Polynomial::<i64>::parse_string(s.to_string());
+	assert_eq!(parse("D(X^3 + 2X)"), Ok(polynomial![2, 0, 3]));
+	assert_eq!(parse("D(D(X^3))"), Ok(polynomial![0, 6]));
+	assert_eq!(parse("compose(X^2 + 1, X - 1)"), Ok(polynomial![2, -2, 1]));
+	assert_eq!(parse("chebyshev(4)"), Ok(polynomial![1, 0, -8, 0, 8]));
+	assert_eq!(parse("chebyshev(2 + 2)"), parse("T_4(X)"));
+	assert_eq!(parse("T_2(2X - 1)"), Ok(polynomial![1, -8, 8]));
+	assert_eq!(parse("T_0(X) + chebyshev(1)"), Ok(polynomial![1, 1]));
+	assert_eq!(parse("bernstein(3, 1)"), Ok(polynomial![0, 3, -6, 3]));
+	assert_eq!(parse("bernstein(2, 3)"), Ok(polynomial![]));
+	assert_eq!(parse("expand((X + 1)^2)"), Ok(polynomial![1, 2, 1]));
+	// The Bernstein polynomials of a given degree sum to 1
+	assert_eq!(
+		parse("bernstein(4,0) + bernstein(4,1) + bernstein(4,2) + bernstein(4,3) + bernstein(4,4)"),
+		Ok(polynomial![1])
+	);
+	// Binomial coefficients are computed in the field
+	assert_eq!(
+		Polynomial::<Fp<3>>::parse_string("bernstein(3, 1)".to_string()),
+		Ok(polynomial![])
+	);
+	let p = Polynomial::<BigRational>::parse_string("compose(T_10(X), X/2)".to_string()).unwrap();
+	assert_eq!(p[10], BigRational::new(BigInt::from(1), BigInt::from(2)));
+
+	// Errors
+	assert_eq!(parse("D(X, X)"), Err("WrongArity(D, 1, 2)".to_string()));
+	assert_eq!(
+		parse("chebyshev(X)"),
+		Err("FunctionFailed(chebyshev, expected a nonnegative integer, got X)".to_string())
+	);
+	assert_eq!(
+		parse("bernstein(3, -1)"),
+		Err("FunctionFailed(bernstein, expected a nonnegative integer, got -1)".to_string())
+	);
+	assert_eq!(parse("T_(X)"), Err("UnboundNames(T_)".to_string()));
+	// Overflows and huge degrees are reported instead of computed
+	assert_eq!(
+		parse("bernstein(70, 35)"),
+		Err("FunctionFailed(bernstein, the coefficients overflow)".to_string())
+	);
+	assert_eq!(
+		parse("chebyshev(64)"),
+		Err("FunctionFailed(chebyshev, the coefficients overflow)".to_string())
+	);
+	assert_eq!(
+		parse("chebyshev(1000000000)"),
+		Err("FunctionFailed(chebyshev, the degree 1000000000 exceeds 10000)".to_string())
+	);
+	assert_eq!(
+		parse("T_1000000000(X)"),
+		Err("FunctionFailed(T_1000000000, the degree 1000000000 exceeds 10000)".to_string())
+	);
+	assert_eq!(
+		Polynomial::<u8>::parse_string("chebyshev(2)".to_string()),
+		Err("FunctionFailed(chebyshev, the coefficients overflow)".to_string())
+	);
+	// 60 C(59, 29) is close to i64::MAX
+	assert_eq!(parse("bernstein(60, 1)").unwrap()[30], -3547937446945842720);
+	// Constants of the environment are products rather than calls
+	let environment = expr::Environment::<i64>::new().constant("D", 3);
+	assert_eq!(
+		Polynomial::parse_string_in("D(X + 1)", &environment),
+		Ok(polynomial![3, 3])
+	);
+	// Subscripted parameters
+	let environment: expr::Environment<i64> = [("a_0", 1), ("a_1", 2)].into_iter().collect();
+	assert_eq!(
+		Polynomial::parse_string_in("a_1 X + a_0", &environment),
+		Ok(polynomial![1, 2])
+	);
+}
+
+#[test]
+fn test_parse_many() {
+	use std::io::{self, BufRead, BufReader, Read};
+
+	use expr::{ParserOptions, Span};
+
+	// Thousands of polynomials, with Windows line endings and empty statements
+	let source: String = (0..2000).map(|k| format!("X + {k}; ;{k}X^2\r\n")).collect();
+	let polynomials: Vec<_> = Polynomial::<i64>::parse_many(source.as_bytes())
+		.collect::<Result<_, _>>()
+		.unwrap();
+	assert_eq!(polynomials.len(), 4000);
+	assert_eq!(polynomials[3998], polynomial![1999, 1]);
+	assert_eq!(polynomials[3999], polynomial![0, 0, 1999]);
+
+	// Errors are located by line and column, in characters, and parsing goes on
+	let source = "X²; 2X\n  ³√X ; (X\nX + 1";
+	let results: Vec<_> = Polynomial::<i32>::parse_many(source.as_bytes()).collect();
+	assert_eq!(results.len(), 5);
+	assert_eq!(results[0], Ok(polynomial![0, 0, 1]));
+	let error = results[2].as_ref().unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"UnsupportedCharacter(√) at line 2, column 4"
+	);
+	assert_eq!(error.span(), Span { start: 4, end: 7 });
+	let error = results[3].as_ref().unwrap_err();
+	assert_eq!((error.line(), error.column()), (Some(2), Some(9)));
+	assert_eq!(results[4], Ok(polynomial![1, 1]));
+	// Deep nestings and huge powers are errors of their own statements
+	let source = format!(
+		"{}X{}\nX^100000000000000; {}1\nX - 1",
+		"(".repeat(100_000),
+		")".repeat(100_000),
+		"X + ".repeat(100_000)
+	);
+	let results: Vec<_> = Polynomial::<i32>::parse_many(source.as_bytes()).collect();
+	assert_eq!(results.len(), 4);
+	assert_eq!(
+		results[0].as_ref().unwrap_err().to_string(),
+		"NestingTooDeep at line 1, column 129"
+	);
+	assert_eq!(
+		results[1].as_ref().unwrap_err().to_string(),
+		"PowerOverflow(Polynomial([0, 1]), 100000000000000) at line 2, column 1"
+	);
+	assert_eq!(results[2], Ok(polynomial![1, 100_000]));
+	assert_eq!(results[3], Ok(polynomial![-1, 1]));
+	// Errors of single strings have no location
+	let error = expr::parse_expr("(X").unwrap_err();
+	assert_eq!((error.line(), error.column()), (None, None));
+
+	let options = ParserOptions::new().variable("t");
+	let results: Vec<_> =
+		Polynomial::<i32>::parse_many_with("t + 1\nt^2".as_bytes(), &options).collect();
+	assert_eq!(results, [Ok(polynomial![1, 1]), Ok(polynomial![0, 0, 1])]);
+
+	// A failing reader ends the iteration with its error
+	struct Failing(usize);
+	impl Read for Failing {
+		fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+			if self.0 == 0 {
+				return Err(io::Error::other("disk failure"));
+			}
+			self.0 = 0;
+			buf[..6].copy_from_slice(b"X + 1\n");
+			Ok(6)
+		}
+	}
+	let reader: Box<dyn BufRead> = Box::new(BufReader::new(Failing(1)));
+	let results: Vec<_> = Polynomial::<i32>::parse_many(reader).collect();
+	assert_eq!(results.len(), 2);
+	assert_eq!(results[0], Ok(polynomial![1, 1]));
+	assert_eq!(
+		results[1].as_ref().unwrap_err().to_string(),
+		"ReadFailed(disk failure) at line 2, column 1"
+	);
+}
+
+#[test]
+fn test_canonical_format() {
+	use std::str::FromStr;
+
+	use expr::Span;
+	use fields::{Fp, Gf256};
+	use num::{BigInt, BigRational};
+	use num_traits::Zero;
+	use traits::CoefficientLiteral;
+
+	fn round_trip<T: CoefficientLiteral>(p: &Polynomial<T>) {
+		let s = p.canonical().to_string();
+		assert_eq!(s.parse::<Polynomial<T>>().as_ref(), Ok(p), "{s}");
+	}
+
+	let mut state = 0x2545_f491_4f6c_dd1du64;
+	let mut random = || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state
+	};
+	fn random_polynomial<T>(random: &mut impl FnMut() -> u64, f: impl Fn(u64) -> T) -> Polynomial<T>
+	where
+		T: CoefficientLiteral,
+	{
+		let degree = random() as usize % 6;
+		Polynomial::from((0..=degree).map(|_| f(random())).collect::<Vec<_>>())
+	}
+
+	for _ in 0..200 {
+		round_trip(&random_polynomial(&mut random, |x| x as i64 >> (x % 64)));
+		round_trip(&random_polynomial(&mut random, |x| (x % 7) as u8));
+		let float = |x: u64| {
+			let f = f64::from_bits(x);
+			// Infinite and NaN coefficients cannot be read back
+			if f.is_finite() {
+				f
+			} else {
+				(1 + (x >> 11)) as f64 / (1u64 << 40) as f64
+			}
+		};
+		round_trip(&random_polynomial(&mut random, float));
+		round_trip(&random_polynomial(&mut random, |x| {
+			Complex::new(float(x), float(x.rotate_left(32)))
+		}));
+		round_trip(&random_polynomial(&mut random, |x| {
+			BigRational::new(BigInt::from(x as i64 % 1000), BigInt::from(1 + x % 97))
+		}));
+		let rational =
+			|x: u64| BigRational::new(BigInt::from(x as i64 % 1000), BigInt::from(1 + x % 97));
+		round_trip(&random_polynomial(&mut random, |x| {
+			Complex::new(rational(x), rational(x.rotate_left(32)))
+		}));
+		round_trip(&random_polynomial(&mut random, Fp::<998244353>::new));
+		round_trip(&random_polynomial(&mut random, |x| Gf256::new(x % 256)));
+	}
+
+	assert_eq!(Polynomial::<i32>::zero().canonical().to_string(), "0");
+	let p = polynomial![i64::MIN, 1, i64::MIN];
+	assert_eq!(
+		p.canonical().to_string(),
+		"-9223372036854775808X^2 + X - 9223372036854775808"
+	);
+	round_trip(&p);
+	let p = polynomial![1.5e-9, -0.1, 1e300];
+	assert_eq!(p.canonical().to_string(), "1e300X^2 - 0.1X + 1.5e-9");
+	round_trip(&p);
+	// Coefficients below the tolerance are kept unless they lead
+	let p = polynomial![1e-12, -5e-324, 1.];
+	assert_eq!(p.canonical().to_string(), "X^2 - 5e-324X + 1e-12");
+	round_trip(&p);
+	let p = polynomial![Complex::new(1e-300, -1e-300), Complex::new(1., 0.)];
+	round_trip(&p);
+	let third = BigRational::new(BigInt::from(1), BigInt::from(3));
+	let p = polynomial![
+		Complex::new(
+			third.clone(),
+			-third.clone() * BigInt::from(6) / BigInt::from(7)
+		),
+		Complex::new(third.clone(), third)
+	];
+	assert_eq!(p.canonical().to_string(), "(1/3+(1/3)i)X + (1/3-(2/7)i)");
+	round_trip(&p);
+	let p = polynomial![
+		Complex::new(0., -1.),
+		Complex::new(-1., 0.),
+		Complex::new(2.5, 1.)
+	];
+	assert_eq!(p.canonical().to_string(), "(2.5+1i)X^2 - X + (0-1i)");
+	round_trip(&p);
+	let p = polynomial![
+		BigRational::from_str("1/2").unwrap(),
+		BigRational::from_str("-3/4").unwrap()
+	];
+	assert_eq!(p.canonical().to_string(), "-3/4X + 1/2");
+	round_trip(&p);
+	let p: Polynomial<Fp<7>> = polynomial![Fp::new(1), Fp::new(6), Fp::new(1)];
+	assert_eq!(p.canonical().to_string(), "X^2 + 6X + 1");
+	let p: Polynomial<Gf256> = polynomial![Gf256::new(0x53), Gf256::new(1)];
+	assert_eq!(p.canonical().to_string(), "X + 0x53");
+
+	// The error keeps its span
+	let error = Polynomial::<i32>::from_str("X + (").unwrap_err();
+	assert_eq!(error.span(), Span { start: 5, end: 5 });
+}
+
+#[test]
+fn test_display() {
+	use num::{BigInt, BigRational};
+
+	let p = polynomial![i128::MIN, 0, i128::MAX];
+	assert_eq!(
+		p.to_string(),
+		format!("Polynomial(2)\n {} X^2\n{}", i128::MAX, i128::MIN)
+	);
+	let p = polynomial![7u8, 255];
+	assert_eq!(format!("{p:+}"), "Polynomial(1)\n+255 X^1\n+7");
+	// Integers ignore the precision and are exact in scientific notation
+	let p = polynomial![-1200i64, 3];
+	assert_eq!(format!("{p:.1}"), "Polynomial(1)\n 3 X^1\n-1200");
+	assert_eq!(format!("{p:e}"), "Polynomial(1)\n 3e0 X^1\n-1.2e3");
+	assert_eq!(format!("{p:5}"), "Polynomial(1)\n     3 X^1\n- 1200");
+
+	let big: BigInt = num_traits::pow(BigInt::from(10), 40) + BigInt::from(1);
+	let p = polynomial![BigInt::from(-2), big.clone()];
+	assert_eq!(p.to_string(), format!("Polynomial(1)\n {big} X^1\n-2"));
+	let p = polynomial![BigRational::new(BigInt::from(-1), BigInt::from(3))];
+	assert_eq!(p.to_string(), "Polynomial(0)\n-1/3");
+	// Big integers and rationals are exact in scientific notation
+	let big = num_traits::pow(BigInt::from(2), 200);
+	assert_eq!(
+		format!("{big:?}"),
+		"1606938044258990275541962092341162602522202993782792835301376"
+	);
+	let p = polynomial![BigInt::from(7), -big];
+	assert_eq!(
+		format!("{p:e}"),
+		"Polynomial(1)\n-1.606938044258990275541962092341162602522202993782792835301376e60 X^1\n+7e0"
+	);
+	assert_eq!(format!("{p:.3e}"), "Polynomial(1)\n-1.607e60 X^1\n+7.000e0");
+	let p = polynomial![BigInt::from(125), BigInt::from(135)];
+	assert_eq!(format!("{p:+.1e}"), "Polynomial(1)\n+1.4e2 X^1\n+1.2e2");
+	let rational = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+	let p = polynomial![
+		rational(-1, 3),
+		rational(1, 1_000_000_000_000),
+		rational(999, 8)
+	];
+	assert_eq!(
+		format!("{p:e}"),
+		"Polynomial(2)\n 1.24875e2 X^2\n+1e-12 X^1\n-3.3333333333333333e-1"
+	);
+	assert_eq!(
+		format!("{p:.2e}"),
+		"Polynomial(2)\n 1.25e2 X^2\n+1.00e-12 X^1\n-3.33e-1"
+	);
+
+	let p = polynomial![0.1f32, 0.25];
+	assert_eq!(format!("{p}"), "Polynomial(1)\n 0.25 X^1\n+0.1");
+	assert_eq!(
+		format!("{p:.3e}"),
+		"Polynomial(1)\n 2.500e-1 X^1\n+1.000e-1"
+	);
+	let p = polynomial![Complex::new(1.5, -2.), Complex::new(0., 1.)];
+	assert_eq!(p.to_string(), "Polynomial(1)\n (0+1i) X^1\n+(1.5-2i)");
+	assert_eq!(
+		format!("{p:.1e}"),
+		"Polynomial(1)\n (0.0e0+1.0e0i) X^1\n+(1.5e0-2.0e0i)"
+	);
+	let p = polynomial![Complex::new(-3, 4), Complex::new(0, 0), Complex::new(2, 0)];
+	assert_eq!(format!("{p:+}"), "Polynomial(2)\n+(2+0i) X^2\n+(-3+4i)");
+	assert_eq!(
+		Polynomial::<i32>::from(vec![]).to_string(),
+		"Polynomial(-Inf)"
+	);
+}
@@ -1,15 +1,25 @@
 use std::{
-	fmt::{Debug, Display},
+	fmt::{self, Debug, Display},
 	ops::{Div, Mul, Sub},
 };
 
-use num::complex::Complex;
-use num_traits::{FromPrimitive, Inv, One, PrimInt, Signed, ToPrimitive, Zero};
+use num::{complex::Complex, BigInt, BigRational, Integer};
+use num_traits::{FromPrimitive, Inv, Num, One, PrimInt, Signed, ToPrimitive, Zero};
+
+use crate::{consts::TOL, Polynomial};
 
 /// Trait for types that have a norm.
 pub trait HasNorm {
 	/// Returns the norm of the implementing object.
 	fn norm(&self) -> f64;
+
+	/// Tells whether the value is considered to be zero when trimming the
+	/// coefficients of a polynomial. Defaults to a norm below `TOL`, exact types
+	/// whose non-zero values can be that small override it.
+	#[inline]
+	fn is_negligible(&self) -> bool {
+		self.norm() <= TOL
+	}
 }
 
 /// Trait for types that can be used as coefficients in a polynomial.
@@ -50,6 +60,29 @@ duplicate::duplicate! {
   impl Primitive for primitive_type {}
 }
 
+impl HasNorm for BigInt {
+	/// The absolute value, which is infinite when it does not fit in an `f64`.
+	#[inline]
+	fn norm(&self) -> f64 {
+		self.to_f64().unwrap_or(f64::INFINITY).abs()
+	}
+}
+impl Primitive for BigInt {}
+
+impl HasNorm for BigRational {
+	#[inline]
+	fn norm(&self) -> f64 {
+		self.to_f64().unwrap_or(f64::INFINITY).abs()
+	}
+
+	/// Only zero is negligible, so that small rationals are kept exactly.
+	#[inline]
+	fn is_negligible(&self) -> bool {
+		self.is_zero()
+	}
+}
+impl Primitive for BigRational {}
+
 impl<T: Primitive> PolyxNum for T {}
 impl<T: Primitive> PolyxNum for Complex<T> {}
 impl<T: Primitive> HasNorm for Complex<T> {
@@ -69,11 +102,310 @@ impl FloatLike for f32 {}
 impl FloatLike for f64 {}
 impl FloatLike for Complex<f32> {}
 impl FloatLike for Complex<f64> {}
+impl FloatLike for BigRational {}
+impl FloatLike for Complex<BigRational> {}
 
 /// A trait for types that behave like signed integers, including having a norm.
 pub trait SignedIntLike: Clone + Debug + PrimInt + Signed + HasNorm {}
 impl<T> SignedIntLike for T where T: Clone + Debug + PrimInt + Signed + HasNorm {}
 
+/// A trait for exact signed integer types, including arbitrary-precision ones
+/// such as `BigInt`, which is enough for the algorithms that only need exact
+/// divisions.
+pub trait IntegerLike: Clone + Debug + Integer + Signed + HasNorm {}
+impl<T> IntegerLike for T where T: Clone + Debug + Integer + Signed + HasNorm {}
+
+/// Coefficient types that can be read from the literals of a polynomial
+/// expression by `Polynomial::parse_string`.
+pub trait CoefficientLiteral: PolyxNum {
+	/// The type in which decimal literals are parsed, e.g. `T` for `Complex<T>`.
+	type Literal: Num + Clone;
+
+	/// Converts a parsed literal to a coefficient.
+	fn from_literal(literal: Self::Literal) -> Self;
+
+	/// Returns the imaginary unit written `i` or `j`, if the type has one.
+	#[inline]
+	fn imaginary_unit() -> Option<Self> {
+		None
+	}
+
+	/// Raises a constant to a power that is not an integer, if it makes sense
+	/// for the type.
+	#[inline]
+	fn pow_constant(&self, _exponent: &Self) -> Option<Self> {
+		None
+	}
+
+	/// Adds two coefficients, or returns `None` if the sum overflows the type.
+	/// Fixed-size integers use checked arithmetic, floats report infinite
+	/// results, and complex numbers check their parts. Defaults to an unchecked
+	/// addition, for the types that cannot overflow.
+	#[inline]
+	fn checked_sum(&self, other: &Self) -> Option<Self> {
+		Some(self.clone() + other.clone())
+	}
+
+	/// Subtracts two coefficients, or returns `None` if the difference
+	/// overflows the type, see `checked_sum`.
+	#[inline]
+	fn checked_difference(&self, other: &Self) -> Option<Self> {
+		Some(self.clone() - other.clone())
+	}
+
+	/// Multiplies two coefficients, or returns `None` if the product overflows
+	/// the type, see `checked_sum`.
+	#[inline]
+	fn checked_product(&self, other: &Self) -> Option<Self> {
+		Some(self.clone() * other.clone())
+	}
+
+	/// Raises a constant to the power `n` by squaring, or returns `None` if it
+	/// overflows the type.
+	#[inline]
+	fn checked_powi(&self, n: u64) -> Option<Self> {
+		pow_by_squaring(self, n, Self::checked_product)
+	}
+
+	/// Writes the coefficient as an expression that parses back to the same
+	/// value, as used by `Polynomial::canonical`. Defaults to `Display`, which
+	/// is exact for integers, rationals and finite fields and gives the
+	/// shortest digits that round-trip for floats.
+	#[inline]
+	fn fmt_literal(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{self}")
+	}
+
+	/// Returns how polynomials with these coefficients are divided by `/`, `%`
+	/// and `gcd` in expressions, if they can be. Floats, rationals, their
+	/// complex numbers and finite fields use `Division::field`, signed and big
+	/// integers `Division::integer`. Defaults to none, as for unsigned integers.
+	#[inline]
+	fn division() -> Option<Division<Self>> {
+		None
+	}
+}
+
+type Operation<T, U> = fn(&Polynomial<T>, &Polynomial<T>) -> U;
+
+/// The division of polynomials used by expressions, which reuses the division
+/// routines of the coefficient type.
+pub struct Division<T> {
+	/// The exact quotient, if there is one.
+	pub(crate) quotient: Operation<T, Option<Polynomial<T>>>,
+	/// The remainder, the divisor being non-zero.
+	pub(crate) remainder: Operation<T, Polynomial<T>>,
+	/// The greatest common divisor.
+	pub(crate) gcd: Operation<T, Polynomial<T>>,
+}
+
+impl<T: FloatLike> Division<T> {
+	/// The division over a field, with `euclidean_division_immutable_float` and
+	/// `gcd_float_immutable`, whose gcd is monic.
+	pub fn field() -> Self {
+		Division {
+			quotient: |p1, p2| {
+				if p2.is_zero() {
+					return None;
+				}
+				let (q, r) = Polynomial::euclidean_division_immutable_float(p1, p2);
+				r.is_zero().then_some(q)
+			},
+			remainder: |p1, p2| Polynomial::euclidean_division_immutable_float(p1, p2).1,
+			gcd: |p1, p2| {
+				if p1.is_zero() && p2.is_zero() {
+					return Polynomial::zero();
+				}
+				Polynomial::gcd_float_immutable(p1, p2)
+			},
+		}
+	}
+}
+
+impl<T: IntegerLike> Division<T> {
+	/// The division over the integers, with `checked_exact_division` and
+	/// `gcd_prs`, whose gcd has a positive leading coefficient.
+	///
+	/// The remainder of two constants is the nonnegative one, as `rem_euclid`.
+	/// Otherwise it is given by `euclidean_division_immutable` when the leading
+	/// coefficient of the divisor is 1 or -1, and by `pseudo_division` else.
+	pub fn integer() -> Self {
+		Division {
+			quotient: Polynomial::checked_exact_division,
+			remainder: |p1, p2| {
+				let lc = p2[p2.degree()].clone();
+				if p1.degree() == 0 && p2.degree() == 0 {
+					let c = p1.0.first().cloned().unwrap_or_else(T::zero);
+					Polynomial::from(vec![c.mod_floor(&lc.abs())])
+				} else if lc.abs().is_one() {
+					Polynomial::euclidean_division_immutable(p1, p2).1
+				} else {
+					Polynomial::pseudo_division(p1, p2).1
+				}
+			},
+			gcd: Polynomial::gcd_prs,
+		}
+	}
+}
+
+/// Raises `x` to the power `n` by squaring with the multiplication `mul`,
+/// which returns `None` on overflow.
+pub(crate) fn pow_by_squaring<T: One + Clone>(
+	x: &T,
+	mut n: u64,
+	mul: impl Fn(&T, &T) -> Option<T>,
+) -> Option<T> {
+	let (mut base, mut result) = (x.clone(), T::one());
+	loop {
+		if n & 1 == 1 {
+			result = mul(&result, &base)?;
+		}
+		n >>= 1;
+		if n == 0 {
+			return Some(result);
+		}
+		base = mul(&base, &base)?;
+	}
+}
+
+/// Writes a primitive number with the shortest of `Display` and `Debug`,
+/// which both round-trip, e.g. `0.1` and `1e300` for floats.
+fn primitive_literal<T: Primitive>(x: &T) -> String {
+	let (display, debug) = (x.to_string(), format!("{x:?}"));
+	if debug.len() < display.len() {
+		debug
+	} else {
+		display
+	}
+}
+
+duplicate::duplicate! {
+  [
+	real_type checked(method, op) real_division complex_division;
+	[f32] [Some(self op other).filter(|x| x.is_finite())] [Some(Division::field())] [Some(Division::field())];
+	[f64] [Some(self op other).filter(|x| x.is_finite())] [Some(Division::field())] [Some(Division::field())];
+	[i8] [self.method(*other)] [Some(Division::integer())] [None];
+	[i16] [self.method(*other)] [Some(Division::integer())] [None];
+	[i32] [self.method(*other)] [Some(Division::integer())] [None];
+	[i64] [self.method(*other)] [Some(Division::integer())] [None];
+	[isize] [self.method(*other)] [Some(Division::integer())] [None];
+	[i128] [self.method(*other)] [Some(Division::integer())] [None];
+	[u8] [self.method(*other)] [None] [None];
+	[u16] [self.method(*other)] [None] [None];
+	[u32] [self.method(*other)] [None] [None];
+	[u64] [self.method(*other)] [None] [None];
+	[usize] [self.method(*other)] [None] [None];
+	[u128] [self.method(*other)] [None] [None];
+	[BigInt] [Some(self op other)] [Some(Division::integer())] [None];
+	[BigRational] [Some(self op other)] [Some(Division::field())] [Some(Division::field())];
+  ]
+  impl CoefficientLiteral for real_type {
+	type Literal = Self;
+
+	#[inline]
+	fn from_literal(literal: Self) -> Self {
+		literal
+	}
+
+	fn pow_constant(&self, exponent: &Self) -> Option<Self> {
+		Self::from_f64(self.to_f64()?.powf(exponent.to_f64()?))
+	}
+
+	#[inline]
+	fn checked_sum(&self, other: &Self) -> Option<Self> {
+		checked([checked_add], [+])
+	}
+
+	#[inline]
+	fn checked_difference(&self, other: &Self) -> Option<Self> {
+		checked([checked_sub], [-])
+	}
+
+	#[inline]
+	fn checked_product(&self, other: &Self) -> Option<Self> {
+		checked([checked_mul], [*])
+	}
+
+	fn fmt_literal(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", primitive_literal(self))
+	}
+
+	#[inline]
+	fn division() -> Option<Division<Self>> {
+		real_division
+	}
+  }
+
+  impl CoefficientLiteral for Complex<real_type> {
+	type Literal = real_type;
+
+	#[inline]
+	fn from_literal(literal: real_type) -> Self {
+		Complex::from(literal)
+	}
+
+	#[inline]
+	fn imaginary_unit() -> Option<Self> {
+		Some(Complex::new(real_type::zero(), real_type::one()))
+	}
+
+	fn pow_constant(&self, exponent: &Self) -> Option<Self> {
+		let to_f64 = |c: &Self| Some(Complex::new(c.re.to_f64()?, c.im.to_f64()?));
+		let c = to_f64(self)?.powc(to_f64(exponent)?);
+		Some(Complex::new(real_type::from_f64(c.re)?, real_type::from_f64(c.im)?))
+	}
+
+	#[inline]
+	fn checked_sum(&self, other: &Self) -> Option<Self> {
+		Some(Complex::new(
+			self.re.checked_sum(&other.re)?,
+			self.im.checked_sum(&other.im)?,
+		))
+	}
+
+	#[inline]
+	fn checked_difference(&self, other: &Self) -> Option<Self> {
+		Some(Complex::new(
+			self.re.checked_difference(&other.re)?,
+			self.im.checked_difference(&other.im)?,
+		))
+	}
+
+	fn checked_product(&self, other: &Self) -> Option<Self> {
+		let (a, b, c, d) = (&self.re, &self.im, &other.re, &other.im);
+		Some(Complex::new(
+			a.checked_product(c)?.checked_difference(&b.checked_product(d)?)?,
+			a.checked_product(d)?.checked_sum(&b.checked_product(c)?)?,
+		))
+	}
+
+	/// Writes `(a+bi)`, or only the real part when the imaginary part is zero.
+	/// A rational imaginary part is written `(a+(p/q)i)`, since `p/qi` would
+	/// divide by `qi`.
+	fn fmt_literal(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.im.is_zero() {
+			return self.re.fmt_literal(f);
+		}
+		let im = primitive_literal(&self.im);
+		let (sign, im) = match im.strip_prefix('-') {
+			Some(im) => ('-', im),
+			None => ('+', im.as_str()),
+		};
+		let re = primitive_literal(&self.re);
+		if im.contains('/') {
+			write!(f, "({re}{sign}({im})i)")
+		} else {
+			write!(f, "({re}{sign}{im}i)")
+		}
+	}
+
+	#[inline]
+	fn division() -> Option<Division<Self>> {
+		complex_division
+	}
+  }
+}
+
 pub trait ToLaTeX {
 	fn to_latex(&self) -> String;
 }
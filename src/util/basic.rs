@@ -114,6 +114,31 @@ where
 		}
 		result
 	}
+
+	/// Evaluates the polynomial and its derivative at the given value in a
+	/// single Horner pass.
+	///
+	/// # Returns
+	///
+	/// The pair `(p(x), p'(x))`.
+	///
+	/// Example:
+	/// ```
+	/// use polyx::Polynomial;
+	/// let p = Polynomial::from(vec![1, 0, 2]);
+	/// assert_eq!(p.eval_with_derivative(2), (9, 8));
+	/// ```
+	#[inline]
+	pub fn eval_with_derivative<U: Into<T>>(&self, x: U) -> (T, T) {
+		let x: T = x.into();
+		let mut result = T::zero();
+		let mut derivative = T::zero();
+		for coef in self.into_iter().rev() {
+			derivative = x.clone() * derivative + result.clone();
+			result = x.clone() * result + coef.clone();
+		}
+		(result, derivative)
+	}
 }
 
 impl<T> Polynomial<T>
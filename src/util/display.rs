@@ -1,21 +1,14 @@
 use std::fmt;
 
-use num::complex::Complex;
-use num_traits::ToPrimitive;
+use num::{complex::Complex, BigInt, BigRational, Integer};
+use num_traits::{One, Signed, ToPrimitive, Zero};
 
 use crate::{
 	consts::{SIGNIF_FIGS, TOL},
-	traits::{Primitive, ToLaTeX},
+	traits::{CoefficientLiteral, HasNorm, Primitive, ToLaTeX},
 	Polynomial,
 };
 
-fn to_complexf64<T>(c: Complex<T>) -> Option<Complex<f64>>
-where
-	T: Clone + ToPrimitive,
-{
-	Some(Complex::new(c.re.to_f64()?, c.im.to_f64()?))
-}
-
 fn pretty_float(x: f64) -> String {
 	// Gives string representing x with n + 1 significant figures
 	if x.abs() < TOL {
@@ -57,193 +50,485 @@ fn pretty_float(x: f64) -> String {
 	}
 }
 
-fn pretty_term_complex(x: Complex<f64>, degree: usize) -> String {
-	// Gives string representing a term of degree `degree` of a polynomial with
-	// complex coefficient x
-	let real_literal = pretty_float(x.re.abs());
-	let imag_literal = pretty_float(x.im.abs());
+duplicate::duplicate! {
+  [machine_type; [f32]; [f64]; [i8]; [i16]; [i32]; [i64]; [isize]; [i128]; [u8]; [u16]; [u32]; [u64]; [usize]; [u128]]
+  /// Rounds the number to SIGNIF_FIGS + 1 significant figures.
+  impl ToLaTeX for machine_type {
+	fn to_latex(&self) -> String {
+		pretty_float(self.to_f64().unwrap())
+	}
+  }
+}
 
-	if x.norm() < TOL {
-		return "".to_string();
+/// Writes all the digits of the integer.
+impl ToLaTeX for BigInt {
+	fn to_latex(&self) -> String {
+		self.to_string()
 	}
-	let xterm = if degree == 1 {
-		"\\ X".to_string()
-	} else {
-		format!("\\ X^{{{}}}", degree)
-	};
+}
 
-	let real_with_foreop = if x.re.abs() < TOL {
-		"".to_string()
-	} else if x.re > 0. {
-		format!("+{}", real_literal)
+/// Writes the rational exactly, as `\frac{p}{q}` unless it is an integer.
+impl ToLaTeX for BigRational {
+	fn to_latex(&self) -> String {
+		if self.is_integer() {
+			return self.numer().to_latex();
+		}
+		let sign = if self.is_negative() { "-" } else { "" };
+		format!("{sign}\\frac{{{}}}{{{}}}", self.numer().abs(), self.denom())
+	}
+}
+
+/// Gives whether a part of a coefficient is negative with the LaTeX code of
+/// its absolute value, or `None` if it is negligible.
+fn latex_part<T: Primitive + ToLaTeX>(x: &T) -> Option<(bool, String)> {
+	if x.is_negligible() {
+		return None;
+	}
+	let literal = x.to_latex();
+	Some(match literal.strip_prefix('-') {
+		Some(abs) => (true, abs.to_string()),
+		None => (false, literal),
+	})
+}
+
+fn pretty_term(
+	re: Option<(bool, String)>,
+	im: Option<(bool, String)>,
+	degree: usize,
+	variable: &str,
+) -> String {
+	// Gives string representing a term of degree `degree` of a polynomial with
+	// a coefficient of real part re and imaginary part im
+	let xterm = if degree == 1 {
+		format!("\\ {variable}")
 	} else {
-		format!("-{}", real_literal)
+		format!("\\ {variable}^{{{}}}", degree)
 	};
-	let imag_with_foreop = if x.im.abs() < TOL {
-		"".to_string()
-	} else if x.im > 0. {
-		format!("+{}i", imag_literal)
-	} else {
-		format!("-{}i", imag_literal)
+	let with_foreop = |part: &Option<(bool, String)>, suffix: &str| match part {
+		None => "".to_string(),
+		Some((false, literal)) => format!("+{literal}{suffix}"),
+		Some((true, literal)) => format!("-{literal}{suffix}"),
 	};
+	let real_with_foreop = with_foreop(&re, "");
+	let imag_with_foreop = with_foreop(&im, "i");
 
-	if degree == 0 {
-		format!("{}{}", real_with_foreop, imag_with_foreop)
-	} else if x.im.abs() < TOL {
-		format!("{}{}", real_with_foreop, xterm)
-	} else if x.re.abs() < TOL {
-		format!("{}{}", imag_with_foreop, xterm)
-	} else if x.re < 0. {
-		if x.im < 0. {
+	match (re, im) {
+		(None, None) => "".to_string(),
+		_ if degree == 0 => format!("{}{}", real_with_foreop, imag_with_foreop),
+		(_, None) => format!("{}{}", real_with_foreop, xterm),
+		(None, _) => format!("{}{}", imag_with_foreop, xterm),
+		(Some((true, real_literal)), Some((true, imag_literal))) => {
 			format!("-({}+{}i){}", real_literal, imag_literal, xterm)
-		} else {
-			format!("+({}{}){}", real_with_foreop, imag_with_foreop, xterm)
 		}
-	} else {
-		format!("+({}{}){}", real_literal, imag_with_foreop, xterm)
+		(Some((true, _)), _) => format!("+({}{}){}", real_with_foreop, imag_with_foreop, xterm),
+		(Some((false, real_literal)), _) => {
+			format!("+({}{}){}", real_literal, imag_with_foreop, xterm)
+		}
 	}
 }
 
-fn pretty_term_real(x: f64, degree: usize) -> String {
-	pretty_term_complex(Complex::from(x), degree)
+/// Joins the terms, from the highest degree, into the LaTeX code of a
+/// polynomial.
+fn join_terms(mut terms: Vec<String>) -> String {
+	if terms.is_empty() {
+		return "0".to_string();
+	}
+	if terms[0].starts_with('+') {
+		terms[0].remove(0);
+	}
+	// Replaces ugly * generated by pretty_float by a more LaTeX-friendly \cdot
+	terms
+		.join("")
+		.chars()
+		.map(|c| {
+			if c == '*' {
+				"\\cdot".to_string()
+			} else {
+				c.to_string()
+			}
+		})
+		.collect()
 }
 
-impl<T> ToLaTeX for Polynomial<T>
-where
-	T: Primitive,
-{
-	/// Gives a LaTeX code to print the polynomial as long as the coefficients can
-	/// be turned into f64 Only the first SIGNIF_FIGS + 1 significant figures are
-	/// printed Only the coefficients with absolute value greater than TOL are
-	/// printed.
+/// A polynomial displayed with a given name for its indeterminate, returned
+/// by `Polynomial::with_variable`. It implements `Display` and `ToLaTeX` like
+/// the polynomial itself, which uses `X`.
+#[derive(Debug, Clone, Copy)]
+pub struct WithVariable<'a, T> {
+	polynomial: &'a Polynomial<T>,
+	variable: &'a str,
+}
+
+impl<T> Polynomial<T> {
+	/// Returns the polynomial displayed with `variable` as the name of its
+	/// indeterminate, e.g. the one given to the parser in `ParserOptions`.
 	///
+	/// # Example
 	/// ```rust
-	/// use polyx::{
-	///   traits::ToLaTeX,
-	///   *,
-	/// };
-	/// let p = polynomial![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-	/// println!("{}", p.to_latex());
+	/// use polyx::{traits::ToLaTeX, *};
+	/// let p = polynomial![1, 0, 2];
+	/// assert_eq!(p.with_variable("t").to_latex(), "2.00\\ t^{2}+1.00");
+	/// assert_eq!(p.with_variable("t").to_string(), "Polynomial(2)\n 2 t^2\n+1");
 	/// ```
-
-	fn to_latex(&self) -> String {
-		let mut length = self.0.len();
-		while length > 0 && self[length - 1].to_f64().unwrap().abs() < TOL {
-			length -= 1;
+	#[inline]
+	pub fn with_variable<'a>(&'a self, variable: &'a str) -> WithVariable<'a, T> {
+		WithVariable {
+			polynomial: self,
+			variable,
 		}
-		if length == 0 {
-			return "0".to_string();
+	}
+}
+
+/// A polynomial written in the canonical text format, returned by
+/// `Polynomial::canonical`.
+#[derive(Debug, Clone, Copy)]
+pub struct Canonical<'a, T>(&'a Polynomial<T>);
+
+impl<T> Polynomial<T> {
+	/// Returns the polynomial written on one line in the syntax of the parser,
+	/// such as `3X^2 - 2X + 1`, so that parsing it gives back the same
+	/// polynomial. The coefficients are written with
+	/// `CoefficientLiteral::fmt_literal`: exactly for integers, rationals and
+	/// finite fields, with full precision for floats and as `(a+bi)` for
+	/// complex numbers. Infinite and NaN coefficients cannot be parsed back.
+	/// Floating point coefficients below the tolerance used to trim
+	/// polynomials are read back exactly, since the parser keeps non-zero
+	/// literals.
+	///
+	/// # Example
+	/// ```rust
+	/// use num::Complex;
+	/// use polyx::*;
+	/// let p = polynomial![1, -2, 3];
+	/// assert_eq!(p.canonical().to_string(), "3X^2 - 2X + 1");
+	/// assert_eq!(p.canonical().to_string().parse(), Ok(p));
+	///
+	/// let p = polynomial![0.1, 0., -1.];
+	/// assert_eq!(p.canonical().to_string(), "-X^2 + 0.1");
+	/// let p = polynomial![Complex::new(1.5, -2.), Complex::new(0., 1.)];
+	/// assert_eq!(p.canonical().to_string(), "(0+1i)X + (1.5-2i)");
+	/// ```
+	#[inline]
+	pub fn canonical(&self) -> Canonical<'_, T> {
+		Canonical(self)
+	}
+}
+
+impl<T> fmt::Display for Canonical<'_, T>
+where
+	T: CoefficientLiteral,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		struct Literal<'a, T>(&'a T);
+		impl<T: CoefficientLiteral> fmt::Display for Literal<'_, T> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				self.0.fmt_literal(f)
+			}
 		}
-		let mut result_str_vec = Vec::with_capacity(length + 1);
-		for index in (0..length).rev() {
-			let c: f64 = self[index].to_f64().unwrap();
-			result_str_vec.push(pretty_term_real(c, index));
+
+		let one = Literal(&T::one()).to_string();
+		let mut first = true;
+		for (degree, c) in self.0 .0.iter().enumerate().rev() {
+			if c.is_zero() {
+				continue;
+			}
+			let literal = Literal(c).to_string();
+			let (negative, literal) = match literal.strip_prefix('-') {
+				Some(literal) => (true, literal),
+				None => (false, literal.as_str()),
+			};
+			let sign = match (first, negative) {
+				(true, false) => "",
+				(true, true) => "-",
+				(false, false) => " + ",
+				(false, true) => " - ",
+			};
+			first = false;
+			write!(f, "{sign}")?;
+			if degree == 0 || literal != one {
+				write!(f, "{literal}")?;
+			}
+			match degree {
+				0 => {}
+				1 => write!(f, "X")?,
+				_ => write!(f, "X^{degree}")?,
+			}
 		}
-		if result_str_vec[0].starts_with('+') {
-			result_str_vec[0].remove(0);
+		if first {
+			write!(f, "0")?;
 		}
-		// Replaces ugly * generated by pretty_float by a more LaTeX-friendly \cdot
-		result_str_vec
-			.join("")
-			.chars()
-			.map(|c| {
-				if c == '*' {
-					"\\cdot".to_string()
-				} else {
-					c.to_string()
-				}
-			})
-			.collect()
+		Ok(())
+	}
+}
+
+impl<T> ToLaTeX for Polynomial<T>
+where
+	T: Primitive + ToLaTeX,
+{
+	fn to_latex(&self) -> String {
+		self.with_variable("X").to_latex()
 	}
 }
 
 impl<T> ToLaTeX for Polynomial<Complex<T>>
 where
-	T: Primitive,
+	T: Primitive + ToLaTeX,
 {
 	fn to_latex(&self) -> String {
-		let mut length = self.0.len();
-		while length > 0 && to_complexf64(self[length - 1].clone()).unwrap().norm() < TOL {
-			length -= 1;
-		}
-		if length == 0 {
-			return "0".to_string();
-		}
-		let mut result_str_vec = Vec::with_capacity(length + 1);
-		for index in (0..length).rev() {
-			let c: Complex<f64> = to_complexf64(self[index].clone()).unwrap();
-			result_str_vec.push(pretty_term_complex(c, index));
-		}
-		if result_str_vec[0].starts_with('+') {
-			result_str_vec[0].remove(0);
-		}
-		// Replaces ugly * generated by pretty_float by a more LaTeX-friendly \cdot
-		result_str_vec
-			.join("")
-			.chars()
-			.map(|c| {
-				if c == '*' {
-					"\\cdot".to_string()
-				} else {
-					c.to_string()
-				}
-			})
-			.collect()
+		self.with_variable("X").to_latex()
 	}
 }
 
-impl<T> fmt::Display for Polynomial<T>
+impl<T> ToLaTeX for WithVariable<'_, T>
 where
-	T: Primitive,
+	T: Primitive + ToLaTeX,
 {
-	/// Allows to display the polynomial in a fancy way
-	/// Example:
+	/// Gives a LaTeX code to print the polynomial. The machine numbers are
+	/// rounded to SIGNIF_FIGS + 1 significant figures, while big integers and
+	/// rationals are written exactly, the latter as `\frac{p}{q}`. Only the
+	/// coefficients that are not negligible are printed.
+	///
 	/// ```rust
-	/// use polyx::*;
+	/// use num::{BigInt, BigRational};
+	/// use polyx::{
+	///   traits::ToLaTeX,
+	///   *,
+	/// };
 	/// let p = polynomial![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-	/// println!("{}", p);
+	/// println!("{}", p.to_latex());
+	///
+	/// let half = BigRational::new(BigInt::from(-1), BigInt::from(2));
+	/// let p = polynomial![half, BigRational::from(BigInt::from(3))];
+	/// assert_eq!(p.to_latex(), "3\\ X-\\frac{1}{2}");
 	/// ```
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		if self.is_empty() {
-			return write!(f, "Polynomial(-Inf)");
-		}
-		let degree = self.degree();
-		if degree == 0 {
-			return write!(
-				f,
-				"{}",
-				format_args!("Polynomial(0)\n {:10.3e}", self[0].to_f64().unwrap())
-			);
-		}
+	fn to_latex(&self) -> String {
+		let terms = (self.polynomial.0.iter().enumerate().rev())
+			.skip_while(|(_, c)| c.is_negligible())
+			.map(|(index, c)| pretty_term(latex_part(c), None, index, self.variable))
+			.collect();
+		join_terms(terms)
+	}
+}
 
-		let mut result_str_vec = Vec::with_capacity(degree + 2);
+impl<T> ToLaTeX for WithVariable<'_, Complex<T>>
+where
+	T: Primitive + ToLaTeX,
+{
+	fn to_latex(&self) -> String {
+		let terms = (self.polynomial.0.iter().enumerate().rev())
+			.map(|(index, c)| (index, latex_part(&c.re), latex_part(&c.im)))
+			.skip_while(|(_, re, im)| re.is_none() && im.is_none())
+			.map(|(index, re, im)| pretty_term(re, im, index, self.variable))
+			.collect();
+		join_terms(terms)
+	}
+}
 
-		result_str_vec.push(format!("Polynomial({degree})"));
+/// The scientific notation of the coefficients of polynomials, as printed by
+/// `{:e}`.
+pub trait ScientificFormat {
+	/// Formats the number in scientific notation with the precision and the
+	/// flags of the formatter.
+	fn fmt_exp(&self, f: &mut fmt::Formatter) -> fmt::Result;
+}
 
-		let c: f64 = self[degree].to_f64().unwrap();
-		if c > TOL {
-			result_str_vec.push(format!(" {:10.3e} X^{degree}", c));
-		} else if c < -TOL {
-			result_str_vec.push(format!("-{:10.3e} X^{degree}", -c));
-		}
+duplicate::duplicate! {
+  [machine_type; [f32]; [f64]; [i8]; [i16]; [i32]; [i64]; [isize]; [i128]; [u8]; [u16]; [u32]; [u64]; [usize]; [u128]]
+  impl ScientificFormat for machine_type {
+	#[inline]
+	fn fmt_exp(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::LowerExp::fmt(self, f)
+	}
+  }
+}
+
+/// Formats the integer exactly, with all its digits unless a precision is
+/// given.
+impl ScientificFormat for BigInt {
+	#[inline]
+	fn fmt_exp(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt_exp_ratio(self, &BigInt::one(), f)
+	}
+}
+
+/// Formats the rational exactly, see `fmt_exp_ratio`.
+impl ScientificFormat for BigRational {
+	#[inline]
+	fn fmt_exp(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt_exp_ratio(self.numer(), self.denom(), f)
+	}
+}
 
-		for index in (1..degree).rev() {
-			let c: f64 = self[index].to_f64().unwrap();
-			if c > TOL {
-				result_str_vec.push(format!("+{:10.3e} X^{index}", c));
-			} else if c < -TOL {
-				result_str_vec.push(format!("-{:10.3e} X^{index}", -c));
+/// Formats `numer / denom`, with a positive `denom`, in scientific notation
+/// from its decimal digits. They are rounded half to even to the precision
+/// of the formatter, like the machine integers, or all written if there are
+/// finitely many, or else rounded to 17 significant digits like an `f64`.
+fn fmt_exp_ratio(numer: &BigInt, denom: &BigInt, f: &mut fmt::Formatter) -> fmt::Result {
+	let ten = |k: i64| num_traits::pow(BigInt::from(10), k.unsigned_abs() as usize);
+	let length = |x: &BigInt| x.to_string().len() as i64;
+	let n = numer.abs();
+	// 10^exponent <= n / denom < 10^(exponent + 1)
+	let mut exponent = length(&n) - length(denom);
+	let below = if exponent >= 0 {
+		n < denom * ten(exponent)
+	} else {
+		n.clone() * ten(exponent) < *denom
+	};
+	if below {
+		exponent -= 1;
+	}
+	let significant = match f.precision() {
+		Some(precision) => precision as i64 + 1,
+		None => {
+			let (mut d, mut m) = (denom.clone(), 0);
+			for p in [2, 5] {
+				let mut k = 0;
+				while d.is_multiple_of(&BigInt::from(p)) {
+					d /= p;
+					k += 1;
+				}
+				m = m.max(k);
+			}
+			if d.is_one() {
+				length(&(n.clone() * ten(m) / denom))
+			} else {
+				17
 			}
 		}
+	};
+	let shift = significant - 1 - exponent;
+	let (n, d) = if shift >= 0 {
+		(n * ten(shift), denom.clone())
+	} else {
+		(n, denom * ten(shift))
+	};
+	let (mut q, r) = n.div_rem(&d);
+	let twice = r * 2;
+	if twice > d || (twice == d && q.is_odd()) {
+		q += 1;
+	}
+	if q == ten(significant) {
+		q /= 10;
+		exponent += 1;
+	}
+	if q.is_zero() {
+		exponent = 0;
+	}
+	let digits = format!("{q:0>width$}", width = significant as usize);
+	let (first, mut rest) = digits.split_at(1);
+	if f.precision().is_none() {
+		rest = rest.trim_end_matches('0');
+	}
+	let point = if rest.is_empty() { "" } else { "." };
+	f.pad_integral(
+		!numer.is_negative(),
+		"",
+		&format!("{first}{point}{rest}e{exponent}"),
+	)
+}
+
+/// Formats a coefficient in scientific notation with `ScientificFormat`.
+struct Exp<'a, T>(&'a T);
+
+impl<T: ScientificFormat> fmt::LowerExp for Exp<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt_exp(f)
+	}
+}
+
+/// Splits a coefficient formatted with a sign into whether it is negative and
+/// its absolute value.
+fn split_sign(s: String) -> (bool, String) {
+	match s.strip_prefix('-') {
+		Some(abs) => (true, abs.to_string()),
+		// NaN has no sign
+		None => (false, s.strip_prefix('+').unwrap_or(&s).to_string()),
+	}
+}
+
+/// Formats a coefficient with the precision requested from the formatter, and
+/// returns whether it is negative with its absolute value.
+fn format_coefficient<T: Primitive>(c: &T, f: &fmt::Formatter) -> (bool, String) {
+	split_sign(match f.precision() {
+		None => format!("{c:+}"),
+		Some(precision) => format!("{c:+.precision$}"),
+	})
+}
 
-		let c: f64 = self[0].to_f64().unwrap();
-		if c > TOL {
-			result_str_vec.push(format!("+{:10.3e}", c));
-		} else if c < -TOL {
-			result_str_vec.push(format!("-{:10.3e}", -c));
+/// Same as `format_coefficient`, in scientific notation.
+fn format_coefficient_exp<T: ScientificFormat>(c: &T, f: &fmt::Formatter) -> (bool, String) {
+	split_sign(match f.precision() {
+		None => format!("{:+e}", Exp(c)),
+		Some(precision) => format!("{:+.precision$e}", Exp(c)),
+	})
+}
+
+/// Formats a complex coefficient as `(a+bi)`, with the parts formatted by
+/// `coefficient`.
+fn format_complex<T>(
+	c: &Complex<T>,
+	f: &fmt::Formatter,
+	coefficient: impl Fn(&T, &fmt::Formatter) -> (bool, String),
+) -> (bool, String) {
+	let (re_negative, re) = coefficient(&c.re, f);
+	let (im_negative, im) = coefficient(&c.im, f);
+	let re_sign = if re_negative { "-" } else { "" };
+	let im_sign = if im_negative { '-' } else { '+' };
+	(false, format!("({re_sign}{re}{im_sign}{im}i)"))
+}
+
+/// Writes the header and the non-negligible terms of the polynomial, one per
+/// line, from the highest degree. `coefficient` gives the sign and the
+/// absolute value of a coefficient, which is padded to the width of the
+/// formatter. The leading term is only signed when negative or for `{:+}`.
+fn fmt_terms<T>(
+	p: &Polynomial<T>,
+	variable: &str,
+	f: &mut fmt::Formatter,
+	coefficient: impl Fn(&T, &fmt::Formatter) -> (bool, String),
+) -> fmt::Result
+where
+	T: HasNorm,
+{
+	if p.is_empty() {
+		return write!(f, "Polynomial(-Inf)");
+	}
+	let degree = p.0.len() - 1;
+	write!(f, "Polynomial({degree})")?;
+	let width = f.width().unwrap_or(0);
+	for (index, c) in p.0.iter().enumerate().rev() {
+		if c.is_negligible() {
+			continue;
+		}
+		let (negative, abs) = coefficient(c, f);
+		let sign = match (negative, index == degree && !f.sign_plus()) {
+			(true, _) => '-',
+			(false, true) => ' ',
+			(false, false) => '+',
+		};
+		write!(f, "\n{sign}{abs:>width$}")?;
+		if index > 0 {
+			write!(f, " {variable}^{index}")?;
 		}
+	}
+	Ok(())
+}
+
+impl<T> fmt::Display for Polynomial<T>
+where
+	T: Primitive,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.with_variable("X").fmt(f)
+	}
+}
 
-		write!(f, "{}", result_str_vec.join("\n"))
+impl<T> fmt::LowerExp for Polynomial<T>
+where
+	T: Primitive + ScientificFormat,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.with_variable("X"), f)
 	}
 }
 
@@ -252,42 +537,82 @@ where
 	T: Primitive,
 {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		if self.is_empty() {
-			return write!(f, "Polynomial(-Inf)");
-		}
-		let degree = self.degree();
-		if degree == 0 {
-			return write!(
-				f,
-				"{}",
-				format_args!(
-					"Polynomial(0)\n {:10.3e}",
-					to_complexf64(self[0].clone()).unwrap()
-				)
-			);
-		}
-
-		let mut result_str_vec = Vec::with_capacity(degree + 2);
+		self.with_variable("X").fmt(f)
+	}
+}
 
-		result_str_vec.push(format!("Polynomial({degree})"));
+impl<T> fmt::LowerExp for Polynomial<Complex<T>>
+where
+	T: Primitive + ScientificFormat,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.with_variable("X"), f)
+	}
+}
 
-		let c: Complex<f64> = to_complexf64(self[degree].clone()).unwrap();
-		if c.norm() > TOL {
-			result_str_vec.push(format!(" ({:10.3e}) X^{degree}", c));
-		}
+impl<T> fmt::Display for WithVariable<'_, T>
+where
+	T: Primitive,
+{
+	/// Displays the polynomial with one term per line. The coefficients are
+	/// printed exactly with their own `Display`, and the precision, width and
+	/// `+` flags of the formatter are applied to each of them. `{:e}` prints
+	/// them in scientific notation instead.
+	///
+	/// Example:
+	/// ```rust
+	/// use polyx::*;
+	/// let p = polynomial![1, -2, 3];
+	/// assert_eq!(p.to_string(), "Polynomial(2)\n 3 X^2\n-2 X^1\n+1");
+	/// let p = polynomial![i128::MAX, 1];
+	/// assert_eq!(
+	///     p.to_string(),
+	///     "Polynomial(1)\n 1 X^1\n+170141183460469231731687303715884105727"
+	/// );
+	///
+	/// let p = polynomial![-0.5, 0., 1250.];
+	/// assert_eq!(format!("{p:.2}"), "Polynomial(2)\n 1250.00 X^2\n-0.50");
+	/// assert_eq!(format!("{p:+.1e}"), "Polynomial(2)\n+1.2e3 X^2\n-5.0e-1");
+	/// assert_eq!(format!("{p:6}"), "Polynomial(2)\n   1250 X^2\n-   0.5");
+	/// ```
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt_terms(self.polynomial, self.variable, f, |c, f| {
+			format_coefficient(c, f)
+		})
+	}
+}
 
-		for index in (1..degree).rev() {
-			let c: Complex<f64> = to_complexf64(self[index].clone()).unwrap();
-			if c.norm() > TOL {
-				result_str_vec.push(format!("+({:10.3e}) X^{index}", c));
-			}
-		}
+impl<T> fmt::LowerExp for WithVariable<'_, T>
+where
+	T: Primitive + ScientificFormat,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt_terms(self.polynomial, self.variable, f, |c, f| {
+			format_coefficient_exp(c, f)
+		})
+	}
+}
 
-		let c: Complex<f64> = to_complexf64(self[0].clone()).unwrap();
-		if c.norm() > TOL {
-			result_str_vec.push(format!("+{:10.3e}", c));
-		}
+impl<T> fmt::Display for WithVariable<'_, Complex<T>>
+where
+	T: Primitive,
+{
+	/// Displays the polynomial with one term per line and the coefficients
+	/// written `(a+bi)`, with the same flags as for real coefficients.
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt_terms(self.polynomial, self.variable, f, |c, f| {
+			format_complex(c, f, format_coefficient)
+		})
+	}
+}
 
-		write!(f, "{}", result_str_vec.join("\n"))
+impl<T> fmt::LowerExp for WithVariable<'_, Complex<T>>
+where
+	T: Primitive + ScientificFormat,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt_terms(self.polynomial, self.variable, f, |c, f| {
+			format_complex(c, f, format_coefficient_exp)
+		})
 	}
 }
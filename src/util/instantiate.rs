@@ -1,8 +1,11 @@
-use std::{fmt::Debug, ops::Sub};
+use std::{
+	fmt::Debug,
+	ops::{Mul, Sub},
+};
 
 use num_traits::{One, Zero};
 
-use crate::{consts::TOL, traits::HasNorm, Polynomial};
+use crate::{traits::HasNorm, Polynomial};
 
 /// Allows to create a polynomial from a list of coefficients.
 #[macro_export]
@@ -61,7 +64,7 @@ where
 {
 	#[inline]
 	fn from(mut values: Vec<T>) -> Self {
-		let effective_len = match values.iter().rposition(|x| x.norm() > TOL) {
+		let effective_len = match values.iter().rposition(|x| !x.is_negligible()) {
 			Some(index) => index + 1,
 			None => 0,
 		};
@@ -73,7 +76,7 @@ where
 impl<T: HasNorm> Polynomial<T> {
 	#[inline]
 	pub fn clean_zeros(&mut self) {
-		let effective_len = match self.into_iter().rposition(|x| x.norm() > TOL) {
+		let effective_len = match self.into_iter().rposition(|x| !x.is_negligible()) {
 			Some(index) => index + 1,
 			None => 0,
 		};
@@ -120,3 +123,65 @@ where
 		Polynomial::from(Vec::from_iter(iter))
 	}
 }
+
+impl<T> Polynomial<T>
+where
+	T: Mul<T, Output = T> + Sub<T, Output = T> + Clone + Zero + One + PartialEq + Debug + HasNorm,
+{
+	/// Multiplies the given polynomials along a balanced product tree, so that
+	/// the Karatsuba multiplication is used on operands of similar degrees.
+	fn product_tree(mut factors: Vec<Self>) -> Self {
+		if factors.is_empty() {
+			return Self::one();
+		}
+		while factors.len() > 1 {
+			let mut iter = factors.into_iter();
+			let mut next = Vec::with_capacity(iter.len().div_ceil(2));
+			while let Some(p1) = iter.next() {
+				match iter.next() {
+					Some(p2) => next.push(p1 * p2),
+					None => next.push(p1),
+				}
+			}
+			factors = next;
+		}
+		factors.pop().unwrap()
+	}
+
+	/// Builds the monic polynomial whose roots are the given values, i.e. the
+	/// product of the `X - r` for every `r` in `roots`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let p = Polynomial::from_roots(&[1, 2, 3]);
+	/// assert_eq!(p, polynomial![-6, 11, -6, 1]);
+	/// ```
+	pub fn from_roots(roots: &[T]) -> Self {
+		Self::product_tree(
+			roots
+				.iter()
+				.map(|r| Polynomial(vec![T::zero() - r.clone(), T::one()]))
+				.collect(),
+		)
+	}
+
+	/// Builds the monic polynomial whose roots are the given values with the
+	/// given multiplicities, i.e. the product of the `(X - r)^m` for every
+	/// `(r, m)` in `roots`.
+	///
+	/// # Example
+	/// ```rust
+	/// use polyx::*;
+	/// let p = Polynomial::from_roots_with_multiplicity(&[(1, 2), (-2, 1)]);
+	/// assert_eq!(p, polynomial![2, -3, 0, 1]);
+	/// ```
+	pub fn from_roots_with_multiplicity(roots: &[(T, usize)]) -> Self {
+		Self::product_tree(
+			roots
+				.iter()
+				.map(|(r, m)| Polynomial(vec![T::zero() - r.clone(), T::one()]).powi(*m))
+				.collect(),
+		)
+	}
+}
@@ -1,3 +1,5 @@
 mod basic;
 mod display;
 mod instantiate;
+
+pub use display::{Canonical, ScientificFormat, WithVariable};